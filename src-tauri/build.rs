@@ -1,3 +1,7 @@
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
 fn main() {
     // 设置跨平台编译环境变量
     if std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default() == "windows" {
@@ -13,5 +17,99 @@ fn main() {
     // 抑制一些跨平台编译的警告
     println!("cargo:rustc-env=SUPPRESS_CROSS_COMPILE_WARNINGS=1");
 
+    // 嵌入构建元数据
+    emit_build_info();
+
     tauri_build::build()
 }
+
+/// 嵌入构建元数据（git 提交、提交时间、目标平台、构建配置与启用的特性）
+fn emit_build_info() {
+    let commit = git(&["rev-parse", "HEAD"]).unwrap_or_default();
+    let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+    println!("cargo:rustc-env=BUILD_GIT_COMMIT={commit}");
+    println!("cargo:rustc-env=BUILD_GIT_DIRTY={dirty}");
+
+    // 切换分支、提交或暂存区变化时重新生成。HEAD 指向分支时提交只会修改该分支的引用
+    // （或打包后的 packed-refs），因此一并监视；不存在的文件会导致每次构建都重新运行，跳过。
+    // 脏标记只是尽力而为：只修改工作区文件而不暂存时不会触发重新生成
+    let mut watched = vec![
+        "HEAD".to_string(),
+        "index".to_string(),
+        "packed-refs".to_string(),
+    ];
+    if let Some(reference) = git(&["symbolic-ref", "-q", "HEAD"]) {
+        watched.push(reference);
+    }
+    for name in watched {
+        if let Some(path) = git(&["rev-parse", "--path-format=absolute", "--git-path", &name]) {
+            if Path::new(&path).exists() {
+                println!("cargo:rerun-if-changed={path}");
+            }
+        }
+    }
+
+    // 构建时间取 HEAD 提交的时间：构建脚本只在上面监视的文件变化时重新运行，
+    // 取当前时间会停留在上次重新运行时。支持通过 SOURCE_DATE_EPOCH 覆盖（可复现构建），
+    // 非 git 仓库中构建时才退回构建脚本运行的时间
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    let timestamp = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .or_else(|| git(&["log", "-1", "--format=%ct"]))
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0)
+        });
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={timestamp}");
+    println!("cargo:rustc-env=BUILD_TIME={}", format_utc(timestamp));
+
+    let target = std::env::var("TARGET").unwrap_or_default();
+    let profile = std::env::var("PROFILE").unwrap_or_default();
+    println!("cargo:rustc-env=BUILD_TARGET={target}");
+    println!("cargo:rustc-env=BUILD_PROFILE={profile}");
+
+    let mut features: Vec<String> = std::env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .map(|feature| feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+    println!("cargo:rustc-env=BUILD_FEATURES={}", features.join(","));
+}
+
+/// 将 Unix 时间戳格式化为 RFC 3339 UTC 时间
+fn format_utc(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // 公历日期换算（Howard Hinnant 的 civil_from_days 算法）
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// 执行 git 命令并返回去除首尾空白的输出，失败时返回 None
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
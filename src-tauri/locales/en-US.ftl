@@ -0,0 +1,141 @@
+# English message catalog
+
+## System info
+
+system-info-group-system = System
+system-info-arch = Architecture
+system-info-platform = Operating system
+system-info-cpu-count = CPU cores
+system-info-total-memory = Total memory
+system-info-unknown-os = Unknown OS
+
+## Menu
+
+menu-settings = Settings
+menu-copy = Copy
+menu-paste = Paste
+menu-select-all = Select All
+menu-quick-restart = Restart
+menu-close-current-window = Close Window
+menu-close-all-windows = Close All Windows
+menu-recent = Open Recent
+menu-recent-empty = No Recent Items
+menu-recent-directory = { $path } (folder)
+menu-clear-recent = Clear Recent Items
+menu-help = Help
+menu-export-diagnostics = Export Diagnostics…
+menu-about = About
+menu-developer = Developer
+menu-toggle-dev-mode = Open Developer Tools
+
+## Dialogs and windows
+
+about-title = About
+about-message =
+    template-tauri-react scaffold
+
+    Version: { $version } ({ $commit })
+    Built: { $build_time }
+    Target: { $target } ({ $profile })
+    Tauri: { $tauri_version }
+    System: { $os } { $arch }
+    Family: { $family }
+about-view-github = View on GitHub
+dialog-ok = OK
+dialog-confirm = Confirm
+dialog-cancel = Cancel
+dialog-all-files = All files
+window-main-title = Tauri React Template - Home
+window-child-title = Child Window
+process-signal-title = Confirm Action
+process-signal-message = Send the { $signal } signal to process { $name } (PID { $pid })?
+diagnostics-title = Export Diagnostics
+diagnostics-preview-message =
+    The diagnostics bundle will contain the following files (sensitive settings are redacted):
+
+    { $files }
+diagnostics-save = Save
+diagnostics-saved = Diagnostics bundle saved to { $path }
+diagnostics-zip-filter = Zip archive
+alert-title = System Alert
+alert-message = Alert rule "{ $name }" triggered: current value { $value }, threshold { $threshold }
+
+## Errors
+
+error-create-loading-window = Failed to create loading window: { $error }
+error-show-loading-window = Failed to show loading window: { $error }
+error-loading-window-not-found = Loading window not found
+error-create-main-window = Failed to create main window: { $error }
+error-main-window-not-found = Main window not found
+error-set-window-title = Failed to set window title: { $error }
+error-create-window = Failed to create window: { $error }
+error-receive-dialog-result = Failed to receive dialog result: { $error }
+error-dialog-window-closed = The window was closed before the dialog returned
+error-create-http-client = Failed to create HTTP client: { $error }
+error-unsupported-http-method = Unsupported HTTP method: { $method }
+error-http-request = HTTP request failed: { $error }
+error-read-response-data = Failed to read response data: { $error }
+error-read-response-text = Failed to read response text: { $error }
+error-monitor-subscription-not-found = Monitor subscription not found: { $id }
+error-process-not-found = Process not found: { $pid }
+error-process-query = Failed to read process information: { $error }
+error-process-changed = Process { $pid } exited and its ID was reused; the signal was not sent
+error-signal-self = Cannot send a signal to the app's own process
+error-receive-confirmation = Failed to receive confirmation: { $error }
+error-send-signal = Failed to send signal: { $signal }
+error-signal-unsupported-platform = Signal not supported on this platform: { $signal }
+error-unsupported-signal = Unsupported signal: { $signal }
+error-task-not-found = Task not found or already finished: { $id }
+error-read-directory = Failed to read directory: { $error }
+error-directory-not-found = Directory not found: { $path }
+error-invalid-pattern = Invalid pattern { $pattern }: { $error }
+error-build-pattern = Failed to build patterns: { $error }
+error-serialize = Failed to serialize results: { $error }
+error-unsupported-export-format = Unsupported export format: { $format }
+error-write-file = Failed to write file: { $error }
+error-unsupported-locale = Unsupported locale: { $locale }
+error-update-menu = Failed to update menu: { $error }
+error-save-settings = Failed to save settings: { $error }
+error-app-config-dir = Failed to resolve app config directory: { $error }
+error-provider-timeout = Timed out while collecting information
+error-invalid-time-range = Invalid time range: start is after end
+error-invalid-alert-rule = Invalid alert rule: threshold and hysteresis must be valid numbers and the mount point must not be empty
+error-alert-rule-not-found = Alert rule not found: { $id }
+error-save-alert-rules = Failed to save alert rules: { $error }
+error-fs-invalid-path = Invalid path: { $path }
+error-fs-access-denied = Access to this path has not been granted: { $path }
+error-fs-root-protected = Cannot remove or rename a granted root: { $path }
+error-fs-grant-not-found = Grant not found: { $path }
+error-fs-io = File operation failed: { $error }
+error-unsupported-encoding = Unsupported encoding: { $encoding }
+error-watch-path = Failed to watch path: { $error }
+error-watch-not-found = Watch not found: { $id }
+error-save-recent-items = Failed to save recent items: { $error }
+error-invalid-recent-limit = The number of recent items must be between 1 and { $max }
+error-app-data-dir = Failed to resolve app data directory: { $error }
+error-invalid-save-path = Invalid save path: { $path }
+error-export-diagnostics = Failed to export diagnostics: { $error }
+error-hash-file = Failed to hash file: { $error }
+error-hash-cancelled = Hashing was cancelled
+error-file-handle-not-found = File handle not found: { $id }
+error-read-range-too-large = Requested range is too large (max { $max })
+error-invalid-drop-rules = Invalid drop rules: the maximum count must be at least 1
+error-unsupported-archive-format = Unsupported archive format (expected .zip, .tar.gz or .tgz): { $path }
+error-invalid-compression-level = Compression level must be between 0 and { $max }
+error-create-archive = Failed to create archive: { $error }
+error-read-archive = Failed to read archive: { $error }
+error-extract-archive = Failed to extract archive: { $error }
+error-task-cancelled = Task was cancelled
+error-invalid-url = Invalid URL: { $url }
+error-url-scheme-not-allowed = Opening "{ $scheme }:" links is not allowed
+error-open-item = Failed to open: { $error }
+error-reveal-unsupported = Revealing files is not supported on this platform
+error-application-not-allowed = Choose { $app } with the application picker before opening items with it
+error-application-not-found = Application not found: { $app }
+error-pick-application-unsupported = Choosing an application is not supported on this platform
+error-unsupported-image-format = Unsupported image format: { $format }
+error-decode-image = Failed to read image: { $error }
+error-encode-image = Failed to write image: { $error }
+error-invalid-thumbnail-size = Thumbnail size must be between 1 and { $max }
+error-invalid-convert-size = Width and height must be between 1 and { $max }
+error-app-cache-dir = Failed to resolve app cache directory: { $error }
@@ -0,0 +1,141 @@
+# 简体中文消息目录（回退语言，需包含全部消息）
+
+## 系统信息
+
+system-info-group-system = 系统
+system-info-arch = 系统架构
+system-info-platform = 操作系统
+system-info-cpu-count = CPU 核心数
+system-info-total-memory = 总内存
+system-info-unknown-os = 未知系统
+
+## 菜单
+
+menu-settings = 设置
+menu-copy = 复制
+menu-paste = 粘贴
+menu-select-all = 全选
+menu-quick-restart = 快速重启
+menu-close-current-window = 关闭当前窗口
+menu-close-all-windows = 关闭所有窗口
+menu-recent = 最近打开
+menu-recent-empty = 无最近打开项
+menu-recent-directory = { $path }（文件夹）
+menu-clear-recent = 清除最近打开
+menu-help = 帮助
+menu-export-diagnostics = 导出诊断信息…
+menu-about = 关于
+menu-developer = 开发者设置
+menu-toggle-dev-mode = 切换到开发者模式
+
+## 对话框与窗口
+
+about-title = 关于
+about-message =
+    template-tauri-react脚手架
+
+    版本信息：{ $version }（{ $commit }）
+    构建时间：{ $build_time }
+    构建目标：{ $target }（{ $profile }）
+    Tauri 版本：{ $tauri_version }
+    系统：{ $os } { $arch }
+    架构：{ $family }
+about-view-github = 查看 GitHub
+dialog-ok = 确认
+dialog-confirm = 确定
+dialog-cancel = 取消
+dialog-all-files = 所有文件
+window-main-title = Tauri React 模板 - 首页
+window-child-title = 子窗口
+process-signal-title = 确认操作
+process-signal-message = 确定要向进程 { $name }（PID { $pid }）发送 { $signal } 信号吗？
+diagnostics-title = 导出诊断信息
+diagnostics-preview-message =
+    诊断包将包含以下文件（设置中的敏感字段已脱敏）：
+
+    { $files }
+diagnostics-save = 保存
+diagnostics-saved = 诊断包已保存到 { $path }
+diagnostics-zip-filter = Zip 压缩包
+alert-title = 系统告警
+alert-message = 告警规则「{ $name }」已触发：当前值 { $value }，阈值 { $threshold }
+
+## 错误
+
+error-create-loading-window = 创建 Loading 窗口失败: { $error }
+error-show-loading-window = 显示 Loading 窗口失败: { $error }
+error-loading-window-not-found = 未找到 Loading 窗口
+error-create-main-window = 创建主窗口失败: { $error }
+error-main-window-not-found = 未找到主窗口
+error-set-window-title = 设置窗口标题失败: { $error }
+error-create-window = 创建窗口失败: { $error }
+error-receive-dialog-result = 接收对话框结果失败: { $error }
+error-dialog-window-closed = 对话框返回前窗口已关闭
+error-create-http-client = 创建 HTTP 客户端失败: { $error }
+error-unsupported-http-method = 不支持的 HTTP 方法: { $method }
+error-http-request = HTTP 请求失败: { $error }
+error-read-response-data = 读取响应数据失败: { $error }
+error-read-response-text = 读取响应文本失败: { $error }
+error-monitor-subscription-not-found = 监控订阅不存在: { $id }
+error-process-not-found = 进程不存在: { $pid }
+error-process-query = 读取进程信息失败: { $error }
+error-process-changed = 进程 { $pid } 已退出且其 ID 已被复用，未发送信号
+error-signal-self = 不能向应用自身进程发送信号
+error-receive-confirmation = 接收确认结果失败: { $error }
+error-send-signal = 发送信号失败: { $signal }
+error-signal-unsupported-platform = 当前平台不支持该信号: { $signal }
+error-unsupported-signal = 不支持的信号: { $signal }
+error-task-not-found = 任务不存在或已结束: { $id }
+error-read-directory = 读取目录失败: { $error }
+error-directory-not-found = 目录不存在: { $path }
+error-invalid-pattern = 无效的匹配模式 { $pattern }: { $error }
+error-build-pattern = 构建匹配模式失败: { $error }
+error-serialize = 序列化结果失败: { $error }
+error-unsupported-export-format = 不支持的导出格式: { $format }
+error-write-file = 写入文件失败: { $error }
+error-unsupported-locale = 不支持的语言: { $locale }
+error-update-menu = 更新菜单失败: { $error }
+error-save-settings = 保存设置失败: { $error }
+error-app-config-dir = 获取应用配置目录失败: { $error }
+error-provider-timeout = 获取信息超时
+error-invalid-time-range = 无效的时间范围：起始时间晚于结束时间
+error-invalid-alert-rule = 无效的告警规则：阈值与回差必须为有效数值，挂载点不能为空
+error-alert-rule-not-found = 告警规则不存在: { $id }
+error-save-alert-rules = 保存告警规则失败: { $error }
+error-fs-invalid-path = 无效的路径: { $path }
+error-fs-access-denied = 没有访问该路径的权限: { $path }
+error-fs-root-protected = 不能删除或重命名已授权的根路径: { $path }
+error-fs-grant-not-found = 授权不存在: { $path }
+error-fs-io = 文件操作失败: { $error }
+error-unsupported-encoding = 不支持的编码: { $encoding }
+error-watch-path = 监听路径失败: { $error }
+error-watch-not-found = 监听不存在: { $id }
+error-save-recent-items = 保存最近打开列表失败: { $error }
+error-invalid-recent-limit = 最近打开条目数必须在 1 到 { $max } 之间
+error-app-data-dir = 获取应用数据目录失败: { $error }
+error-invalid-save-path = 无效的保存路径: { $path }
+error-export-diagnostics = 导出诊断信息失败: { $error }
+error-hash-file = 计算文件摘要失败: { $error }
+error-hash-cancelled = 文件摘要计算已取消
+error-file-handle-not-found = 文件句柄不存在: { $id }
+error-read-range-too-large = 请求的范围过大（最大 { $max }）
+error-invalid-drop-rules = 拖放规则无效：最大数量至少为 1
+error-unsupported-archive-format = 不支持的归档格式（应为 .zip、.tar.gz 或 .tgz）: { $path }
+error-invalid-compression-level = 压缩级别必须在 0 到 { $max } 之间
+error-create-archive = 创建归档失败: { $error }
+error-read-archive = 读取归档失败: { $error }
+error-extract-archive = 解压归档失败: { $error }
+error-task-cancelled = 任务已取消
+error-invalid-url = 无效的 URL: { $url }
+error-url-scheme-not-allowed = 不允许打开 "{ $scheme }:" 链接
+error-open-item = 打开失败: { $error }
+error-reveal-unsupported = 当前平台不支持在文件管理器中显示
+error-application-not-allowed = 请先通过应用选择对话框选择 { $app }，再用它打开
+error-application-not-found = 未找到应用: { $app }
+error-pick-application-unsupported = 当前平台不支持选择应用
+error-unsupported-image-format = 不支持的图片格式: { $format }
+error-decode-image = 读取图片失败: { $error }
+error-encode-image = 写入图片失败: { $error }
+error-invalid-thumbnail-size = 缩略图尺寸必须在 1 到 { $max } 之间
+error-invalid-convert-size = 宽度与高度必须在 1 到 { $max } 之间
+error-app-cache-dir = 无法获取应用缓存目录: { $error }
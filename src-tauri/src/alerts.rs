@@ -0,0 +1,412 @@
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use sysinfo::Disks;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+
+use crate::i18n::t;
+use crate::monitor::{now_millis, MonitorSample, MonitorState};
+
+/// 告警触发或恢复时发送的事件名
+pub const ALERT_EVENT: &str = "alert";
+/// 告警规则文件名（位于应用配置目录）
+const RULES_FILE: &str = "alert-rules.json";
+/// 规则检查间隔（毫秒）
+const EVALUATE_INTERVAL_MS: u64 = 5000;
+/// 最多保留的告警历史条数
+const MAX_HISTORY: usize = 500;
+
+// 告警指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AlertMetric {
+    /// CPU 总使用率（百分比）
+    Cpu,
+    /// 内存使用率（百分比）
+    MemoryPercent,
+    /// 1 分钟负载均值
+    Load,
+    /// 指定挂载点的可用空间（字节）
+    DiskFree { mount_point: String },
+    /// 指定挂载点的可用空间（百分比）
+    DiskFreePercent { mount_point: String },
+}
+
+// 比较方式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    Above,
+    Below,
+}
+
+// 告警规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRule {
+    /// 规则 ID，保存时为空则自动生成
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub metric: AlertMetric,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    /// 条件需持续满足的时长（秒）
+    #[serde(default)]
+    pub duration_secs: u64,
+    /// 回差：触发后需越过阈值并超出该幅度才视为恢复
+    #[serde(default)]
+    pub hysteresis: f64,
+    /// 冷却时间（秒）：两次触发之间的最小间隔
+    #[serde(default)]
+    pub cooldown_secs: u64,
+    /// 触发时是否弹出原生对话框
+    #[serde(default)]
+    pub show_dialog: bool,
+}
+
+// 告警状态
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertStatus {
+    Firing,
+    Resolved,
+}
+
+// 告警记录（同时作为事件负载）
+#[derive(Debug, Clone, Serialize)]
+pub struct AlertRecord {
+    /// 发生时间（Unix 毫秒）
+    timestamp: u64,
+    rule_id: String,
+    rule_name: String,
+    status: AlertStatus,
+    metric: AlertMetric,
+    value: f64,
+    threshold: f64,
+}
+
+/// 规则的运行时状态
+#[derive(Default)]
+struct RuleState {
+    /// 条件开始持续满足的时间
+    breach_since: Option<Instant>,
+    firing: bool,
+    last_fired: Option<Instant>,
+}
+
+/// 告警状态（托管在 Tauri State 中）
+#[derive(Default)]
+pub struct AlertState {
+    rules: Mutex<Vec<AlertRule>>,
+    runtime: Mutex<HashMap<String, RuleState>>,
+    history: Mutex<VecDeque<AlertRecord>>,
+}
+
+impl AlertState {
+    fn save_rules(&self, app: &AppHandle) -> Result<(), String> {
+        let rules = self.rules.lock().unwrap();
+        let path = rules_path(app)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| t!("error-save-alert-rules", error = e))?;
+        }
+        let content = serde_json::to_string_pretty(&*rules)
+            .map_err(|e| t!("error-save-alert-rules", error = e))?;
+        std::fs::write(path, content).map_err(|e| t!("error-save-alert-rules", error = e))
+    }
+}
+
+/**
+ * 告警规则文件路径
+ */
+fn rules_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(RULES_FILE))
+        .map_err(|e| t!("error-app-config-dir", error = e))
+}
+
+/**
+ * 启动告警检查：加载规则，然后订阅监控采样并逐条检查
+ */
+pub fn start(app: AppHandle) {
+    let rules: Vec<AlertRule> = rules_path(&app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    *app.state::<AlertState>().rules.lock().unwrap() = rules;
+
+    tauri::async_runtime::spawn(async move {
+        // 采样回调持有订阅表锁，检查操作转交给当前任务处理
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        app.state::<MonitorState>().subscribe(
+            EVALUATE_INTERVAL_MS,
+            Box::new(move |sample| tx.send(sample.clone()).is_ok()),
+        );
+
+        while let Some(sample) = rx.recv().await {
+            evaluate(&app, &sample);
+        }
+    });
+}
+
+/**
+ * 按采样结果检查所有启用的规则
+ */
+fn evaluate(app: &AppHandle, sample: &MonitorSample) {
+    let alerts = app.state::<AlertState>();
+    let rules: Vec<AlertRule> = alerts
+        .rules
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|rule| rule.enabled)
+        .cloned()
+        .collect();
+    if rules.is_empty() {
+        return;
+    }
+
+    let needs_disks = rules.iter().any(|rule| {
+        matches!(
+            rule.metric,
+            AlertMetric::DiskFree { .. } | AlertMetric::DiskFreePercent { .. }
+        )
+    });
+    let disks = needs_disks.then(Disks::new_with_refreshed_list);
+
+    let now = Instant::now();
+    let mut records = Vec::new();
+    {
+        let mut runtime = alerts.runtime.lock().unwrap();
+        for rule in &rules {
+            let state = runtime.entry(rule.id.clone()).or_default();
+            let Some(value) = metric_value(&rule.metric, sample, disks.as_ref()) else {
+                state.breach_since = None;
+                continue;
+            };
+
+            let (breached, recovered) = match rule.comparison {
+                Comparison::Above => (
+                    value > rule.threshold,
+                    value < rule.threshold - rule.hysteresis,
+                ),
+                Comparison::Below => (
+                    value < rule.threshold,
+                    value > rule.threshold + rule.hysteresis,
+                ),
+            };
+
+            let status = if state.firing {
+                if !recovered {
+                    continue;
+                }
+                state.firing = false;
+                state.breach_since = None;
+                AlertStatus::Resolved
+            } else {
+                if !breached {
+                    state.breach_since = None;
+                    continue;
+                }
+                let since = *state.breach_since.get_or_insert(now);
+                let sustained =
+                    now.duration_since(since) >= Duration::from_secs(rule.duration_secs);
+                let cooled_down = state.last_fired.is_none_or(|last| {
+                    now.duration_since(last) >= Duration::from_secs(rule.cooldown_secs)
+                });
+                if !sustained || !cooled_down {
+                    continue;
+                }
+                state.firing = true;
+                state.last_fired = Some(now);
+                AlertStatus::Firing
+            };
+
+            records.push((
+                rule,
+                AlertRecord {
+                    timestamp: now_millis(),
+                    rule_id: rule.id.clone(),
+                    rule_name: rule.name.clone(),
+                    status,
+                    metric: rule.metric.clone(),
+                    value,
+                    threshold: rule.threshold,
+                },
+            ));
+        }
+    }
+
+    for (rule, record) in records {
+        if let Err(e) = app.emit(ALERT_EVENT, &record) {
+            log::warn!("Failed to emit alert: {e}");
+        }
+        if rule.show_dialog && matches!(record.status, AlertStatus::Firing) {
+            app.dialog()
+                .message(t!(
+                    "alert-message",
+                    name = rule.name,
+                    value = format_value(&rule.metric, record.value),
+                    threshold = format_value(&rule.metric, rule.threshold)
+                ))
+                .title(t!("alert-title"))
+                .kind(MessageDialogKind::Warning)
+                .show(|_| {});
+        }
+
+        let mut history = alerts.history.lock().unwrap();
+        if history.len() >= MAX_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(record);
+    }
+}
+
+/**
+ * 读取指标当前值，挂载点不存在时返回 None
+ */
+fn metric_value(
+    metric: &AlertMetric,
+    sample: &MonitorSample,
+    disks: Option<&Disks>,
+) -> Option<f64> {
+    let disk = |mount_point: &str| {
+        disks?
+            .list()
+            .iter()
+            .find(|disk| disk.mount_point() == std::path::Path::new(mount_point))
+    };
+
+    match metric {
+        AlertMetric::Cpu => Some(f64::from(sample.cpu_usage)),
+        AlertMetric::MemoryPercent => (sample.memory_total > 0)
+            .then(|| sample.memory_used as f64 / sample.memory_total as f64 * 100.0),
+        AlertMetric::Load => Some(sample.load_average.one),
+        AlertMetric::DiskFree { mount_point } => {
+            disk(mount_point).map(|disk| disk.available_space() as f64)
+        }
+        AlertMetric::DiskFreePercent { mount_point } => disk(mount_point)
+            .filter(|disk| disk.total_space() > 0)
+            .map(|disk| disk.available_space() as f64 / disk.total_space() as f64 * 100.0),
+    }
+}
+
+/**
+ * 按指标类型格式化数值
+ */
+fn format_value(metric: &AlertMetric, value: f64) -> String {
+    match metric {
+        AlertMetric::Cpu | AlertMetric::MemoryPercent | AlertMetric::DiskFreePercent { .. } => {
+            format!("{value:.1}%")
+        }
+        AlertMetric::Load => format!("{value:.2}"),
+        AlertMetric::DiskFree { .. } => format!("{:.1} GB", value / 1024.0 / 1024.0 / 1024.0),
+    }
+}
+
+/**
+ * 获取告警规则
+ */
+#[tauri::command]
+pub async fn get_alert_rules(alerts: State<'_, AlertState>) -> Result<Vec<AlertRule>, String> {
+    Ok(alerts.rules.lock().unwrap().clone())
+}
+
+/**
+ * 新增或更新告警规则（按 ID 匹配），返回保存后的规则
+ */
+#[tauri::command]
+pub async fn save_alert_rule(
+    app: AppHandle,
+    alerts: State<'_, AlertState>,
+    mut rule: AlertRule,
+) -> Result<AlertRule, String> {
+    let mount_point = match &rule.metric {
+        AlertMetric::DiskFree { mount_point } | AlertMetric::DiskFreePercent { mount_point } => {
+            Some(mount_point.as_str())
+        }
+        _ => None,
+    };
+    if !rule.threshold.is_finite()
+        || !rule.hysteresis.is_finite()
+        || rule.hysteresis < 0.0
+        || mount_point.is_some_and(str::is_empty)
+    {
+        return Err(t!("error-invalid-alert-rule"));
+    }
+    {
+        let mut rules = alerts.rules.lock().unwrap();
+        if rule.id.is_empty() {
+            // 同一毫秒内保存多条规则时追加序号，保证 ID 唯一
+            let base = format!("rule-{}", now_millis());
+            rule.id = base.clone();
+            let mut suffix = 1;
+            while rules.iter().any(|existing| existing.id == rule.id) {
+                rule.id = format!("{base}-{suffix}");
+                suffix += 1;
+            }
+        }
+        match rules.iter_mut().find(|existing| existing.id == rule.id) {
+            Some(existing) => *existing = rule.clone(),
+            None => rules.push(rule.clone()),
+        }
+    }
+    // 规则变更后重新计算持续时间与告警状态
+    alerts.runtime.lock().unwrap().remove(&rule.id);
+    alerts.save_rules(&app)?;
+
+    Ok(rule)
+}
+
+/**
+ * 删除告警规则
+ */
+#[tauri::command]
+pub async fn delete_alert_rule(
+    app: AppHandle,
+    alerts: State<'_, AlertState>,
+    id: String,
+) -> Result<(), String> {
+    {
+        let mut rules = alerts.rules.lock().unwrap();
+        let len = rules.len();
+        rules.retain(|rule| rule.id != id);
+        if rules.len() == len {
+            return Err(t!("error-alert-rule-not-found", id = id));
+        }
+    }
+    alerts.runtime.lock().unwrap().remove(&id);
+    alerts.save_rules(&app)
+}
+
+/**
+ * 获取告警历史（按时间倒序）
+ */
+#[tauri::command]
+pub async fn get_alert_history(
+    alerts: State<'_, AlertState>,
+    limit: Option<usize>,
+) -> Result<Vec<AlertRecord>, String> {
+    let history = alerts.history.lock().unwrap();
+    Ok(history
+        .iter()
+        .rev()
+        .take(limit.unwrap_or(MAX_HISTORY))
+        .cloned()
+        .collect())
+}
+
+/**
+ * 清空告警历史
+ */
+#[tauri::command]
+pub async fn clear_alert_history(alerts: State<'_, AlertState>) -> Result<(), String> {
+    alerts.history.lock().unwrap().clear();
+    Ok(())
+}
@@ -0,0 +1,775 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager, State};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+use crate::tasks::{CancelToken, TaskRegistry};
+
+/// 默认压缩级别
+const DEFAULT_LEVEL: u32 = 6;
+/// 最大压缩级别
+const MAX_LEVEL: u32 = 9;
+/// 进度事件的最小发送间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+// 归档格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveFormat {
+    Zip,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// 根据文件扩展名判断格式
+    fn detect(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+// 创建归档选项
+#[derive(Debug, Default, Deserialize)]
+pub struct ArchiveOptions {
+    /// 归档格式，未指定时按输出文件扩展名判断
+    pub format: Option<ArchiveFormat>,
+    /// 压缩级别（0–9，默认 6）
+    pub level: Option<u32>,
+}
+
+// 归档条目
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveEntry {
+    /// 归档内的路径（以 `/` 分隔）
+    name: String,
+    is_dir: bool,
+    /// 解压后的大小（字节）
+    size: u64,
+    /// 压缩后的大小（仅 zip）
+    compressed_size: Option<u64>,
+}
+
+// 归档任务事件
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum ArchiveEvent {
+    /// 已处理的字节数（解压 tar.gz 时按压缩数据计算）
+    Progress {
+        processed: u64,
+        total: u64,
+        current: String,
+    },
+    Finished {
+        entry_count: u64,
+        bytes: u64,
+        /// 解压时因路径不安全或为链接而跳过的条目
+        skipped: Vec<String>,
+    },
+    Cancelled,
+    Failed {
+        message: String,
+    },
+}
+
+struct Progress {
+    token: CancelToken,
+    channel: Channel<ArchiveEvent>,
+    processed: u64,
+    total: u64,
+    /// 设置时以该计数作为进度（解压 tar.gz 时为已读取的压缩字节数）
+    source: Option<Arc<AtomicU64>>,
+    last_progress: Instant,
+}
+
+impl Progress {
+    fn new(token: CancelToken, channel: Channel<ArchiveEvent>) -> Self {
+        Self {
+            token,
+            channel,
+            processed: 0,
+            total: 0,
+            source: None,
+            last_progress: Instant::now(),
+        }
+    }
+
+    fn advance(&mut self, bytes: u64, current: &str) {
+        self.processed += bytes;
+        if self.last_progress.elapsed() < PROGRESS_INTERVAL {
+            return;
+        }
+        self.last_progress = Instant::now();
+        let processed = self
+            .source
+            .as_ref()
+            .map(|source| source.load(Ordering::Relaxed))
+            .unwrap_or(self.processed);
+        let _ = self.channel.send(ArchiveEvent::Progress {
+            processed,
+            total: self.total,
+            current: current.to_string(),
+        });
+    }
+}
+
+/// 读取时报告进度并检查取消的包装
+struct Tracked<'a, R> {
+    inner: R,
+    progress: &'a mut Progress,
+    current: &'a str,
+}
+
+impl<R: Read> Read for Tracked<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.progress.token.is_cancelled() {
+            return Err(io::Error::other(t!("error-task-cancelled")));
+        }
+        let read = self.inner.read(buf)?;
+        self.progress.advance(read as u64, self.current);
+        Ok(read)
+    }
+}
+
+/// 统计已读取字节数的包装
+struct Counting<R> {
+    inner: R,
+    count: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for Counting<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.count.fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+// 待归档的文件或目录
+struct Source {
+    path: PathBuf,
+    /// 归档内的路径
+    name: String,
+    is_dir: bool,
+    size: u64,
+}
+
+/**
+ * 收集待归档的条目（不跟随符号链接，跳过输出文件本身）
+ */
+fn collect_sources(roots: &[PathBuf], output: &Path) -> Result<Vec<Source>, String> {
+    let mut sources = Vec::new();
+    for root in roots {
+        let base = root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| t!("error-fs-invalid-path", path = root.display().to_string()))?;
+        let mut stack = vec![(root.clone(), base)];
+
+        while let Some((path, name)) = stack.pop() {
+            if path == output {
+                continue;
+            }
+            let metadata =
+                fs::symlink_metadata(&path).map_err(|e| t!("error-create-archive", error = e))?;
+            if metadata.is_dir() {
+                let entries =
+                    fs::read_dir(&path).map_err(|e| t!("error-create-archive", error = e))?;
+                for entry in entries.filter_map(|entry| entry.ok()) {
+                    let child = format!("{name}/{}", entry.file_name().to_string_lossy());
+                    stack.push((entry.path(), child));
+                }
+            } else if !metadata.is_file() {
+                log::warn!("Skipping non-regular file {}", path.display());
+                continue;
+            }
+            sources.push(Source {
+                path,
+                name,
+                is_dir: metadata.is_dir(),
+                size: if metadata.is_file() {
+                    metadata.len()
+                } else {
+                    0
+                },
+            });
+        }
+    }
+    sources.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(sources)
+}
+
+#[cfg(unix)]
+fn unix_mode(path: &Path) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).ok().map(|m| m.permissions().mode())
+}
+
+#[cfg(not(unix))]
+fn unix_mode(_path: &Path) -> Option<u32> {
+    None
+}
+
+#[cfg(unix)]
+fn set_unix_mode(path: &Path, mode: u32) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = fs::set_permissions(path, fs::Permissions::from_mode(mode & 0o777));
+}
+
+#[cfg(not(unix))]
+fn set_unix_mode(_path: &Path, _mode: u32) {}
+
+fn write_zip(
+    sources: &[Source],
+    output: &Path,
+    level: u32,
+    progress: &mut Progress,
+) -> Result<(), String> {
+    let error = |e: &dyn std::fmt::Display| t!("error-create-archive", error = e);
+    let file = File::create(output).map_err(|e| error(&e))?;
+    let mut zip = ZipWriter::new(file);
+    let options = if level == 0 {
+        SimpleFileOptions::default().compression_method(CompressionMethod::Stored)
+    } else {
+        SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .compression_level(Some(level as i64))
+    };
+
+    for source in sources {
+        let mut options = options.large_file(source.size >= u32::MAX as u64);
+        if let Some(mode) = unix_mode(&source.path) {
+            options = options.unix_permissions(mode);
+        }
+        if source.is_dir {
+            zip.add_directory(source.name.as_str(), options)
+                .map_err(|e| error(&e))?;
+            continue;
+        }
+        zip.start_file(source.name.as_str(), options)
+            .map_err(|e| error(&e))?;
+        let mut reader = Tracked {
+            inner: File::open(&source.path).map_err(|e| error(&e))?,
+            progress: &mut *progress,
+            current: &source.name,
+        };
+        io::copy(&mut reader, &mut zip).map_err(|e| error(&e))?;
+    }
+
+    zip.finish().map_err(|e| error(&e))?;
+    Ok(())
+}
+
+fn write_tar_gz(
+    sources: &[Source],
+    output: &Path,
+    level: u32,
+    progress: &mut Progress,
+) -> Result<(), String> {
+    let error = |e: &dyn std::fmt::Display| t!("error-create-archive", error = e);
+    let file = File::create(output).map_err(|e| error(&e))?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::new(level)));
+
+    for source in sources {
+        if source.is_dir {
+            builder
+                .append_dir(source.name.as_str(), &source.path)
+                .map_err(|e| error(&e))?;
+            continue;
+        }
+        let file = File::open(&source.path).map_err(|e| error(&e))?;
+        let metadata = file.metadata().map_err(|e| error(&e))?;
+        let mut header = tar::Header::new_gnu();
+        header.set_metadata(&metadata);
+        let reader = Tracked {
+            inner: file,
+            progress: &mut *progress,
+            current: &source.name,
+        };
+        builder
+            .append_data(&mut header, source.name.as_str(), reader)
+            .map_err(|e| error(&e))?;
+    }
+
+    let mut encoder = builder.into_inner().map_err(|e| error(&e))?;
+    encoder.flush().map_err(|e| error(&e))?;
+    encoder.finish().map_err(|e| error(&e))?;
+    Ok(())
+}
+
+/**
+ * 将归档内的路径安全地拼接到目标目录：只允许普通路径分量，拒绝绝对路径、盘符与 `..`
+ *
+ * Windows 创建的归档可能使用 `\` 分隔路径，在所有平台上都按分隔符处理
+ */
+fn safe_join(destination: &Path, name: &str) -> Option<PathBuf> {
+    let name = name.replace('\\', "/");
+    let is_drive = |part: &str| {
+        let bytes = part.as_bytes();
+        bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+    };
+    if name.split('/').next().is_some_and(is_drive) {
+        return None;
+    }
+
+    let mut path = destination.to_path_buf();
+    let mut has_component = false;
+    for component in Path::new(&name).components() {
+        match component {
+            Component::Normal(part) => {
+                path.push(part);
+                has_component = true;
+            }
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    has_component.then_some(path)
+}
+
+/**
+ * 在目标目录内创建目录：先确认最近一级已存在的祖先规范化后位于目标目录内再创建，
+ * 防止通过目标目录中已有的符号链接在外部创建目录；不在目标目录内时返回 false
+ */
+fn prepare_dir(destination: &Path, dir: &Path) -> io::Result<bool> {
+    let mut existing = dir;
+    while fs::symlink_metadata(existing).is_err() {
+        match existing.parent() {
+            Some(parent) => existing = parent,
+            None => return Ok(false),
+        }
+    }
+    // 悬空的符号链接无法规范化，视为不安全
+    let inside = existing
+        .canonicalize()
+        .is_ok_and(|existing| existing.starts_with(destination));
+    if !inside {
+        return Ok(false);
+    }
+
+    fs::create_dir_all(dir)?;
+    Ok(dir.canonicalize()?.starts_with(destination))
+}
+
+/**
+ * 创建父目录（见 `prepare_dir`），并确认目标本身不是符号链接
+ */
+fn prepare_parent(destination: &Path, path: &Path) -> io::Result<bool> {
+    let Some(parent) = path.parent() else {
+        return Ok(false);
+    };
+    let is_symlink = fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_symlink());
+    Ok(!is_symlink && prepare_dir(destination, parent)?)
+}
+
+/**
+ * 判断条目是否被选中（选中目录时包含其下全部条目）
+ */
+fn is_selected(name: &str, selection: Option<&[String]>) -> bool {
+    let Some(selection) = selection else {
+        return true;
+    };
+    let name = name.trim_end_matches('/');
+    selection.iter().any(|selected| {
+        let selected = selected.trim_end_matches('/');
+        name == selected
+            || name
+                .strip_prefix(selected)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+// 解压统计
+#[derive(Default)]
+struct ExtractSummary {
+    entry_count: u64,
+    bytes: u64,
+    skipped: Vec<String>,
+}
+
+fn extract_zip(
+    archive_path: &Path,
+    destination: &Path,
+    selection: Option<&[String]>,
+    progress: &mut Progress,
+) -> Result<ExtractSummary, String> {
+    let error = |e: &dyn std::fmt::Display| t!("error-extract-archive", error = e);
+    let file = File::open(archive_path).map_err(|e| error(&e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| error(&e))?;
+
+    let mut total = 0;
+    for i in 0..archive.len() {
+        let entry = archive.by_index_raw(i).map_err(|e| error(&e))?;
+        if is_selected(entry.name(), selection) {
+            total += entry.size();
+        }
+    }
+    progress.total = total;
+
+    let mut summary = ExtractSummary::default();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(|e| error(&e))?;
+        let name = entry.name().to_string();
+        if !is_selected(&name, selection) {
+            continue;
+        }
+        let target = (!entry.is_symlink())
+            .then(|| safe_join(destination, &name))
+            .flatten();
+        let Some(target) = target else {
+            summary.skipped.push(name);
+            continue;
+        };
+
+        if entry.is_dir() {
+            if !prepare_dir(destination, &target).map_err(|e| error(&e))? {
+                summary.skipped.push(name);
+                continue;
+            }
+        } else {
+            if !prepare_parent(destination, &target).map_err(|e| error(&e))? {
+                summary.skipped.push(name);
+                continue;
+            }
+            let mut output = File::create(&target).map_err(|e| error(&e))?;
+            let mut reader = Tracked {
+                inner: &mut entry,
+                progress: &mut *progress,
+                current: &name,
+            };
+            summary.bytes += io::copy(&mut reader, &mut output).map_err(|e| error(&e))?;
+            // 只恢复文件权限，避免只读目录导致后续条目无法写入
+            if let Some(mode) = entry.unix_mode() {
+                set_unix_mode(&target, mode);
+            }
+        }
+        summary.entry_count += 1;
+    }
+    Ok(summary)
+}
+
+fn extract_tar_gz(
+    archive_path: &Path,
+    destination: &Path,
+    selection: Option<&[String]>,
+    progress: &mut Progress,
+) -> Result<ExtractSummary, String> {
+    let error = |e: &dyn std::fmt::Display| t!("error-extract-archive", error = e);
+    let file = File::open(archive_path).map_err(|e| error(&e))?;
+    let count = Arc::new(AtomicU64::new(0));
+    progress.total = file.metadata().map(|m| m.len()).unwrap_or(0);
+    progress.source = Some(count.clone());
+
+    let mut archive = tar::Archive::new(GzDecoder::new(Counting { inner: file, count }));
+    let mut summary = ExtractSummary::default();
+    for entry in archive.entries().map_err(|e| error(&e))? {
+        let mut entry = entry.map_err(|e| error(&e))?;
+        let name = entry
+            .path()
+            .map_err(|e| error(&e))?
+            .to_string_lossy()
+            .to_string();
+        if !is_selected(&name, selection) {
+            continue;
+        }
+        let entry_type = entry.header().entry_type();
+        let target = (entry_type.is_file() || entry_type.is_dir())
+            .then(|| safe_join(destination, &name))
+            .flatten();
+        let Some(target) = target else {
+            summary.skipped.push(name);
+            continue;
+        };
+
+        if entry_type.is_dir() {
+            if !prepare_dir(destination, &target).map_err(|e| error(&e))? {
+                summary.skipped.push(name);
+                continue;
+            }
+        } else {
+            if !prepare_parent(destination, &target).map_err(|e| error(&e))? {
+                summary.skipped.push(name);
+                continue;
+            }
+            let mut output = File::create(&target).map_err(|e| error(&e))?;
+            let mut reader = Tracked {
+                inner: &mut entry,
+                progress: &mut *progress,
+                current: &name,
+            };
+            summary.bytes += io::copy(&mut reader, &mut output).map_err(|e| error(&e))?;
+            if let Ok(mode) = entry.header().mode() {
+                set_unix_mode(&target, mode);
+            }
+        }
+        summary.entry_count += 1;
+    }
+    Ok(summary)
+}
+
+/**
+ * 结束任务：根据结果发送完成、取消或失败事件
+ */
+fn finish(progress: &Progress, result: Result<ExtractSummary, String>) {
+    let event = match result {
+        _ if progress.token.is_cancelled() => ArchiveEvent::Cancelled,
+        Ok(summary) => ArchiveEvent::Finished {
+            entry_count: summary.entry_count,
+            bytes: summary.bytes,
+            skipped: summary.skipped,
+        },
+        Err(message) => ArchiveEvent::Failed { message },
+    };
+    let _ = progress.channel.send(event);
+}
+
+/**
+ * 将文件与目录打包为 zip 或 tar.gz（路径需位于已授权范围内），通过 Channel 推送进度，返回任务 ID
+ *
+ * 失败或取消时删除未完成的归档
+ */
+#[tauri::command]
+pub async fn create_archive(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    tasks: State<'_, TaskRegistry>,
+    paths: Vec<String>,
+    output: String,
+    options: Option<ArchiveOptions>,
+    on_event: Channel<ArchiveEvent>,
+) -> Result<u32, String> {
+    let output = scoped_fs::resolve(&settings, &output)?;
+    let options = options.unwrap_or_default();
+    let format = options
+        .format
+        .or_else(|| ArchiveFormat::detect(&output))
+        .ok_or_else(|| {
+            t!(
+                "error-unsupported-archive-format",
+                path = output.display().to_string()
+            )
+        })?;
+    let level = options.level.unwrap_or(DEFAULT_LEVEL);
+    if level > MAX_LEVEL {
+        return Err(t!("error-invalid-compression-level", max = MAX_LEVEL));
+    }
+    let roots = paths
+        .iter()
+        .map(|path| scoped_fs::resolve(&settings, path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (task_id, token) = tasks.register();
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut progress = Progress::new(token, on_event);
+        let result = collect_sources(&roots, &output).and_then(|sources| {
+            progress.total = sources.iter().map(|source| source.size).sum();
+            match format {
+                ArchiveFormat::Zip => write_zip(&sources, &output, level, &mut progress),
+                ArchiveFormat::TarGz => write_tar_gz(&sources, &output, level, &mut progress),
+            }
+            .map(|_| ExtractSummary {
+                entry_count: sources.len() as u64,
+                bytes: progress.processed,
+                skipped: Vec::new(),
+            })
+        });
+        if result.is_err() || progress.token.is_cancelled() {
+            let _ = fs::remove_file(&output);
+        }
+        finish(&progress, result);
+        app.state::<TaskRegistry>().finish(task_id);
+    });
+
+    Ok(task_id)
+}
+
+/**
+ * 列出归档中的条目
+ */
+#[tauri::command]
+pub async fn list_archive(
+    settings: State<'_, SettingsState>,
+    path: String,
+) -> Result<Vec<ArchiveEntry>, String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+    let format = ArchiveFormat::detect(&path).ok_or_else(|| {
+        t!(
+            "error-unsupported-archive-format",
+            path = path.display().to_string()
+        )
+    })?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let error = |e: &dyn std::fmt::Display| t!("error-read-archive", error = e);
+        let file = File::open(&path).map_err(|e| error(&e))?;
+        let mut entries = Vec::new();
+
+        match format {
+            ArchiveFormat::Zip => {
+                let mut archive = ZipArchive::new(file).map_err(|e| error(&e))?;
+                for i in 0..archive.len() {
+                    let entry = archive.by_index_raw(i).map_err(|e| error(&e))?;
+                    entries.push(ArchiveEntry {
+                        name: entry.name().to_string(),
+                        is_dir: entry.is_dir(),
+                        size: entry.size(),
+                        compressed_size: Some(entry.compressed_size()),
+                    });
+                }
+            }
+            ArchiveFormat::TarGz => {
+                let mut archive = tar::Archive::new(GzDecoder::new(file));
+                for entry in archive.entries().map_err(|e| error(&e))? {
+                    let entry = entry.map_err(|e| error(&e))?;
+                    entries.push(ArchiveEntry {
+                        name: entry.path().map_err(|e| error(&e))?.display().to_string(),
+                        is_dir: entry.header().entry_type().is_dir(),
+                        size: entry.size(),
+                        compressed_size: None,
+                    });
+                }
+            }
+        }
+        Ok(entries)
+    })
+    .await
+    .map_err(|e| t!("error-read-archive", error = e))?
+}
+
+/**
+ * 解压归档到目录（路径需位于已授权范围内），entries 为空时解压全部条目，通过 Channel 推送进度，返回任务 ID
+ *
+ * 绝对路径、包含 `..` 的条目以及符号链接与硬链接会被跳过；已存在的文件会被覆盖
+ */
+#[tauri::command]
+pub async fn extract_archive(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    tasks: State<'_, TaskRegistry>,
+    path: String,
+    destination: String,
+    entries: Option<Vec<String>>,
+    on_event: Channel<ArchiveEvent>,
+) -> Result<u32, String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+    let format = ArchiveFormat::detect(&path).ok_or_else(|| {
+        t!(
+            "error-unsupported-archive-format",
+            path = path.display().to_string()
+        )
+    })?;
+    let destination = scoped_fs::resolve(&settings, &destination)?;
+    fs::create_dir_all(&destination).map_err(|e| t!("error-extract-archive", error = e))?;
+    let destination = destination
+        .canonicalize()
+        .map_err(|e| t!("error-extract-archive", error = e))?;
+
+    let (task_id, token) = tasks.register();
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut progress = Progress::new(token, on_event);
+        let selection = entries.as_deref().filter(|entries| !entries.is_empty());
+        let result = match format {
+            ArchiveFormat::Zip => extract_zip(&path, &destination, selection, &mut progress),
+            ArchiveFormat::TarGz => extract_tar_gz(&path, &destination, selection, &mut progress),
+        };
+        finish(&progress, result);
+        app.state::<TaskRegistry>().finish(task_id);
+    });
+
+    Ok(task_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_join_accepts_normal_paths() {
+        let destination = Path::new("/dest");
+        assert_eq!(
+            safe_join(destination, "a/b.txt"),
+            Some(PathBuf::from("/dest/a/b.txt"))
+        );
+        assert_eq!(
+            safe_join(destination, "./a/./b/"),
+            Some(PathBuf::from("/dest/a/b"))
+        );
+        assert_eq!(
+            safe_join(destination, "a\\b.txt"),
+            Some(PathBuf::from("/dest/a/b.txt"))
+        );
+    }
+
+    #[test]
+    fn safe_join_rejects_escaping_paths() {
+        let destination = Path::new("/dest");
+        assert_eq!(safe_join(destination, "../evil.txt"), None);
+        assert_eq!(safe_join(destination, "a/../../evil.txt"), None);
+        assert_eq!(safe_join(destination, "a/.."), None);
+        assert_eq!(safe_join(destination, "..\\evil.txt"), None);
+        assert_eq!(safe_join(destination, "/etc/passwd"), None);
+        assert_eq!(safe_join(destination, "\\evil.txt"), None);
+        assert_eq!(safe_join(destination, "C:\\evil.txt"), None);
+        assert_eq!(safe_join(destination, "c:/evil.txt"), None);
+        assert_eq!(safe_join(destination, ""), None);
+        assert_eq!(safe_join(destination, "."), None);
+    }
+
+    #[test]
+    fn is_selected_matches_entries_and_directories() {
+        let selection = vec!["docs".to_string(), "src/main.rs".to_string()];
+        let selection = Some(selection.as_slice());
+        assert!(is_selected("docs/", selection));
+        assert!(is_selected("docs/readme.md", selection));
+        assert!(is_selected("src/main.rs", selection));
+        assert!(!is_selected("docs-old/readme.md", selection));
+        assert!(!is_selected("src/lib.rs", selection));
+        assert!(!is_selected("src", selection));
+        assert!(is_selected("anything", None));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn prepare_dir_rejects_symlinked_directories() {
+        let root = std::env::temp_dir().join(format!("archive-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dest")).unwrap();
+        fs::create_dir_all(root.join("outside")).unwrap();
+        let root = root.canonicalize().unwrap();
+        let destination = root.join("dest");
+        std::os::unix::fs::symlink(root.join("outside"), destination.join("link")).unwrap();
+        std::os::unix::fs::symlink(root.join("missing"), destination.join("dangling")).unwrap();
+
+        // 条目路径经过目标目录中的符号链接时拒绝，且不在外部创建目录
+        assert!(!prepare_dir(&destination, &destination.join("link/sub")).unwrap());
+        assert!(!root.join("outside/sub").exists());
+        assert!(!prepare_dir(&destination, &destination.join("dangling/sub")).unwrap());
+        assert!(!root.join("missing").exists());
+        assert!(!prepare_parent(&destination, &destination.join("link")).unwrap());
+
+        assert!(prepare_dir(&destination, &destination.join("a/b")).unwrap());
+        assert!(destination.join("a/b").is_dir());
+        assert!(prepare_parent(&destination, &destination.join("a/c/file.txt")).unwrap());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
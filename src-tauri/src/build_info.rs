@@ -0,0 +1,54 @@
+use serde::Serialize;
+
+// 构建元数据（由 build.rs 在编译期嵌入）
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildInfo {
+    /// git 提交哈希，非 git 仓库中构建时为空
+    pub git_commit: String,
+    /// 构建时工作区是否有未提交的修改
+    pub git_dirty: bool,
+    /// 构建时间（Unix 秒）：HEAD 提交的时间，可由 SOURCE_DATE_EPOCH 覆盖
+    pub build_timestamp: u64,
+    /// 构建时间（RFC 3339 UTC），与 `build_timestamp` 相同
+    pub build_time: String,
+    /// 目标平台三元组，如 `x86_64-pc-windows-msvc`
+    pub target: String,
+    /// 构建配置（debug / release）
+    pub profile: String,
+    /// 启用的 cargo 特性
+    pub features: Vec<String>,
+    pub tauri_version: String,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        Self {
+            git_commit: env!("BUILD_GIT_COMMIT").to_string(),
+            git_dirty: env!("BUILD_GIT_DIRTY") == "true",
+            build_timestamp: env!("BUILD_TIMESTAMP").parse().unwrap_or(0),
+            build_time: env!("BUILD_TIME").to_string(),
+            target: env!("BUILD_TARGET").to_string(),
+            profile: env!("BUILD_PROFILE").to_string(),
+            features: env!("BUILD_FEATURES")
+                .split(',')
+                .filter(|feature| !feature.is_empty())
+                .map(str::to_string)
+                .collect(),
+            tauri_version: tauri::VERSION.to_string(),
+        }
+    }
+
+    /// 简短的提交标识，如 `1a2b3c4` 或 `1a2b3c4-dirty`（用于“关于”菜单）
+    #[cfg(desktop)]
+    pub fn short_commit(&self) -> String {
+        if self.git_commit.is_empty() {
+            return "unknown".to_string();
+        }
+        let short: String = self.git_commit.chars().take(7).collect();
+        if self.git_dirty {
+            format!("{short}-dirty")
+        } else {
+            short
+        }
+    }
+}
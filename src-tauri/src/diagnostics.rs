@@ -0,0 +1,483 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_dialog::{DialogExt, FilePath};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::handlers::{self, HttpResponse};
+use crate::i18n::t;
+use crate::monitor::{now_millis, MonitorState};
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+
+/// 最多保留的 HTTP 错误记录数
+const MAX_HTTP_ERRORS: usize = 200;
+/// 汇总中列出的最近错误数
+const RECENT_HTTP_ERRORS: usize = 20;
+/// 每个日志文件最多打包的字节数（取末尾部分）
+const MAX_LOG_BYTES: u64 = 512 * 1024;
+/// 设置中需要脱敏的键名单词（按 camelCase、`_`、`-` 拆分后逐个完整匹配）
+const SENSITIVE_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "passwd",
+    "secret",
+    "key",
+    "auth",
+    "authorization",
+    "cookie",
+    "credential",
+    "credentials",
+];
+/// 脱敏后的占位符
+const REDACTED: &str = "[REDACTED]";
+
+// HTTP 错误记录
+#[derive(Debug, Clone, Serialize)]
+pub struct HttpErrorRecord {
+    /// 发生时间（Unix 毫秒）
+    timestamp: u64,
+    method: String,
+    /// 去除查询参数与认证信息后的 URL
+    url: String,
+    /// 响应状态码，请求未完成时为空
+    status: Option<u16>,
+    error: Option<String>,
+}
+
+// HTTP 错误汇总
+#[derive(Debug, Serialize)]
+pub struct HttpErrorSummary {
+    total: usize,
+    by_status: BTreeMap<String, usize>,
+    by_host: BTreeMap<String, usize>,
+    recent: Vec<HttpErrorRecord>,
+}
+
+/// 最近一次预览的诊断包内容（托管在 Tauri State 中），导出时使用同一份内容
+#[derive(Default)]
+pub struct DiagnosticsPreview(Mutex<Option<Vec<DiagnosticsEntry>>>);
+
+/// 最近的 HTTP 错误记录（托管在 Tauri State 中）
+#[derive(Default)]
+pub struct HttpErrorLog(Mutex<VecDeque<HttpErrorRecord>>);
+
+impl HttpErrorLog {
+    /// 记录请求失败或非 2xx 响应
+    pub fn record_result(&self, method: &str, url: &str, result: &Result<HttpResponse, String>) {
+        let (status, error) = match result {
+            Ok(response) if response.success => return,
+            Ok(response) => (Some(response.status), None),
+            Err(error) => (None, Some(error.clone())),
+        };
+
+        let mut records = self.0.lock().unwrap();
+        if records.len() >= MAX_HTTP_ERRORS {
+            records.pop_front();
+        }
+        records.push_back(HttpErrorRecord {
+            timestamp: now_millis(),
+            method: method.to_uppercase(),
+            url: sanitize_url(url),
+            status,
+            error,
+        });
+    }
+
+    pub fn summary(&self) -> HttpErrorSummary {
+        let records = self.0.lock().unwrap();
+        let mut by_status = BTreeMap::new();
+        let mut by_host = BTreeMap::new();
+
+        for record in records.iter() {
+            let status = record
+                .status
+                .map_or_else(|| "error".to_string(), |status| status.to_string());
+            *by_status.entry(status).or_insert(0) += 1;
+
+            let host = reqwest::Url::parse(&record.url)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string))
+                .unwrap_or_default();
+            *by_host.entry(host).or_insert(0) += 1;
+        }
+
+        HttpErrorSummary {
+            total: records.len(),
+            by_status,
+            by_host,
+            recent: records
+                .iter()
+                .rev()
+                .take(RECENT_HTTP_ERRORS)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+// 诊断包中的单个文件
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsEntry {
+    /// 包内路径，如 `system.json`、`logs/app.log`
+    name: String,
+    content: String,
+}
+
+// 窗口信息
+#[derive(Debug, Serialize)]
+struct WindowInfo {
+    label: String,
+    title: Option<String>,
+    url: String,
+    visible: Option<bool>,
+    focused: Option<bool>,
+    width: Option<u32>,
+    height: Option<u32>,
+    scale_factor: Option<f64>,
+}
+
+/**
+ * 去除 URL 中的查询参数、片段与认证信息，避免泄露令牌
+ */
+fn sanitize_url(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(mut parsed) => {
+            parsed.set_query(None);
+            parsed.set_fragment(None);
+            let _ = parsed.set_username("");
+            let _ = parsed.set_password(None);
+            parsed.to_string()
+        }
+        Err(_) => url.split(['?', '#']).next().unwrap_or_default().to_string(),
+    }
+}
+
+/**
+ * 判断键名是否敏感：拆分为单词后任一单词在敏感列表中（`apiKey` 敏感，`author` 不敏感）
+ */
+fn is_sensitive_key(key: &str) -> bool {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lower = false;
+    for c in key.chars() {
+        if !c.is_alphanumeric() {
+            words.push(std::mem::take(&mut word));
+            previous_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_lower {
+            words.push(std::mem::take(&mut word));
+        }
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        word.extend(c.to_lowercase());
+    }
+    words.push(word);
+
+    words
+        .iter()
+        .any(|word| SENSITIVE_KEYS.contains(&word.as_str()))
+}
+
+/**
+ * 递归脱敏 JSON 中键名包含敏感词的字段
+ */
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_key(key) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+fn json_entry(name: &str, value: &impl Serialize) -> Result<DiagnosticsEntry, String> {
+    let content =
+        serde_json::to_string_pretty(value).map_err(|e| t!("error-serialize", error = e))?;
+    Ok(DiagnosticsEntry {
+        name: name.to_string(),
+        content,
+    })
+}
+
+/**
+ * 列出所有窗口
+ */
+fn list_windows(app: &AppHandle) -> Vec<WindowInfo> {
+    let mut windows: Vec<WindowInfo> = app
+        .webview_windows()
+        .into_iter()
+        .map(|(label, window)| {
+            let size = window.inner_size().ok();
+            WindowInfo {
+                label,
+                title: window.title().ok(),
+                url: window
+                    .url()
+                    .map(|url| sanitize_url(url.as_str()))
+                    .unwrap_or_default(),
+                visible: window.is_visible().ok(),
+                focused: window.is_focused().ok(),
+                width: size.map(|size| size.width),
+                height: size.map(|size| size.height),
+                scale_factor: window.scale_factor().ok(),
+            }
+        })
+        .collect();
+    windows.sort_by(|a, b| a.label.cmp(&b.label));
+    windows
+}
+
+/**
+ * 读取日志目录中各日志文件的末尾部分
+ */
+fn collect_logs(app: &AppHandle) -> Vec<DiagnosticsEntry> {
+    let Ok(log_dir) = app.path().app_log_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(log_dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "log"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let content = read_tail(&path, MAX_LOG_BYTES).ok()?;
+            let name = path.file_name()?.to_string_lossy().to_string();
+            Some(DiagnosticsEntry {
+                name: format!("logs/{name}"),
+                content,
+            })
+        })
+        .collect()
+}
+
+/**
+ * 读取文件最后 `limit` 字节
+ */
+fn read_tail(path: &std::path::Path, limit: u64) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(limit)))?;
+
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)?;
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+/**
+ * 收集诊断信息，返回将写入诊断包的文件列表
+ */
+pub async fn collect(app: &AppHandle) -> Result<Vec<DiagnosticsEntry>, String> {
+    let monitor = app.state::<MonitorState>();
+    let mut entries = vec![
+        json_entry("version.json", &handlers::get_version_info().await?)?,
+        json_entry(
+            "system.json",
+            &handlers::get_system_info(app.clone(), app.state()).await?,
+        )?,
+        json_entry("host.json", &handlers::get_host_info(monitor).await?)?,
+        json_entry("disks.json", &handlers::get_disk_info().await?)?,
+        // 不访问外部服务查询公网 IP
+        json_entry(
+            "network.json",
+            &handlers::get_network_info(Some(false)).await?,
+        )?,
+    ];
+
+    let mut settings = serde_json::to_value(app.state::<SettingsState>().get())
+        .map_err(|e| t!("error-serialize", error = e))?;
+    redact(&mut settings);
+    entries.push(json_entry("settings.json", &settings)?);
+
+    entries.push(json_entry(
+        "http-errors.json",
+        &app.state::<HttpErrorLog>().summary(),
+    )?);
+    entries.push(json_entry("windows.json", &list_windows(app))?);
+    entries.extend(collect_logs(app));
+
+    Ok(entries)
+}
+
+/**
+ * 将文件列表写入 zip
+ */
+fn write_bundle(path: &std::path::Path, entries: &[DiagnosticsEntry]) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| t!("error-write-file", error = e))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for entry in entries {
+        zip.start_file(entry.name.as_str(), options)
+            .map_err(|e| t!("error-write-file", error = e))?;
+        zip.write_all(entry.content.as_bytes())
+            .map_err(|e| t!("error-write-file", error = e))?;
+    }
+
+    zip.finish()
+        .map_err(|e| t!("error-write-file", error = e))?;
+    Ok(())
+}
+
+/**
+ * 弹出保存对话框选择诊断包位置，用户取消时返回 None
+ */
+async fn pick_bundle_path(app: &AppHandle) -> Result<Option<PathBuf>, String> {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .file()
+        .set_file_name(format!("diagnostics-{}.zip", now_millis() / 1000))
+        .add_filter(t!("diagnostics-zip-filter"), &["zip"])
+        .save_file(move |file_path| {
+            let _ = tx.send(file_path);
+        });
+
+    let file_path = rx
+        .await
+        .map_err(|e| t!("error-receive-dialog-result", error = e))?;
+    match file_path {
+        Some(FilePath::Path(path)) => Ok(Some(path)),
+        Some(FilePath::Url(url)) => url
+            .to_file_path()
+            .map(Some)
+            .map_err(|_| t!("error-invalid-save-path", path = url)),
+        None => Ok(None),
+    }
+}
+
+/**
+ * 格式化文件大小
+ */
+#[cfg(desktop)]
+fn format_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{bytes} B")
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+/**
+ * 菜单入口：收集诊断信息，预览包含的文件，确认后选择位置并保存
+ */
+#[cfg(desktop)]
+pub async fn export_with_preview(app: AppHandle) {
+    use tauri_plugin_dialog::{MessageDialogButtons, MessageDialogKind};
+
+    let result = async {
+        let entries = collect(&app).await?;
+        let files = entries
+            .iter()
+            .map(|entry| format!("• {} ({})", entry.name, format_size(entry.content.len())))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        app.dialog()
+            .message(t!("diagnostics-preview-message", files = files))
+            .title(t!("diagnostics-title"))
+            .kind(MessageDialogKind::Info)
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                t!("diagnostics-save"),
+                t!("dialog-cancel"),
+            ))
+            .show(move |confirmed| {
+                let _ = tx.send(confirmed);
+            });
+        let confirmed = rx
+            .await
+            .map_err(|e| t!("error-receive-confirmation", error = e))?;
+        if !confirmed {
+            return Ok(None);
+        }
+
+        let Some(path) = pick_bundle_path(&app).await? else {
+            return Ok(None);
+        };
+        write_bundle(&path, &entries)?;
+        Ok::<_, String>(Some(path))
+    }
+    .await;
+
+    let (kind, message) = match result {
+        Ok(Some(path)) => (
+            MessageDialogKind::Info,
+            t!("diagnostics-saved", path = path.display()),
+        ),
+        Ok(None) => return,
+        Err(error) => {
+            log::error!("Failed to export diagnostics: {error}");
+            (
+                MessageDialogKind::Error,
+                t!("error-export-diagnostics", error = error),
+            )
+        }
+    };
+    app.dialog()
+        .message(message)
+        .title(t!("diagnostics-title"))
+        .kind(kind)
+        .show(|_| {});
+}
+
+/**
+ * 预览诊断包内容（文件名与内容），随后的导出写入的正是这份内容
+ */
+#[tauri::command]
+pub async fn preview_diagnostics(
+    app: AppHandle,
+    preview: State<'_, DiagnosticsPreview>,
+) -> Result<Vec<DiagnosticsEntry>, String> {
+    let entries = collect(&app).await?;
+    *preview.0.lock().unwrap() = Some(entries.clone());
+    Ok(entries)
+}
+
+/**
+ * 导出诊断包：写入最近一次预览的内容（未预览时重新收集）；
+ * 指定的路径需位于已授权范围内，未指定时弹出保存对话框。返回保存路径，用户取消时返回 None
+ */
+#[tauri::command]
+pub async fn export_diagnostics(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    preview: State<'_, DiagnosticsPreview>,
+    path: Option<String>,
+) -> Result<Option<String>, String> {
+    let path = match path {
+        Some(path) => scoped_fs::resolve(&settings, &path)?,
+        None => match pick_bundle_path(&app).await? {
+            Some(path) => path,
+            None => return Ok(None),
+        },
+    };
+
+    let previewed = preview.0.lock().unwrap().take();
+    let entries = match previewed {
+        Some(entries) => entries,
+        None => collect(&app).await?,
+    };
+    write_bundle(&path, &entries)?;
+    Ok(Some(path.display().to_string()))
+}
@@ -0,0 +1,350 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+use crate::tasks::{CancelToken, TaskRegistry};
+
+/// 进度事件的最小发送间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+/// 最多保留的错误条数
+const MAX_ERRORS: usize = 100;
+
+// 目录扫描选项
+#[derive(Debug, Default, Deserialize)]
+pub struct ScanOptions {
+    /// 结果树保留的最大深度（默认 3，更深的层级只计入大小）
+    pub max_depth: Option<usize>,
+    /// 每个节点保留的最大子项数（默认 100，按大小降序截断）
+    pub max_children: Option<usize>,
+    /// 返回最大文件的数量（默认 50）
+    pub top_files: Option<usize>,
+    /// 是否跟随符号链接（默认否）
+    pub follow_symlinks: Option<bool>,
+}
+
+// 目录大小树节点
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeNode {
+    name: String,
+    path: String,
+    is_dir: bool,
+    /// 总大小（字节）
+    size: u64,
+    file_count: u64,
+    dir_count: u64,
+    /// 子项，按大小降序排列
+    children: Vec<SizeNode>,
+}
+
+// 文件条目
+#[derive(Debug, Clone, Serialize)]
+pub struct FileEntry {
+    path: String,
+    size: u64,
+}
+
+// 扫描错误（如无权限）
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanError {
+    path: String,
+    message: String,
+}
+
+// 扫描事件
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum ScanEvent {
+    Progress {
+        files: u64,
+        dirs: u64,
+        bytes: u64,
+        current: String,
+    },
+    /// 某个顶层子目录扫描完成
+    Entry {
+        node: SizeNode,
+    },
+    Finished {
+        root: SizeNode,
+        largest_files: Vec<FileEntry>,
+        errors: Vec<ScanError>,
+        error_count: u64,
+    },
+    Cancelled,
+    Failed {
+        message: String,
+    },
+}
+
+impl SizeNode {
+    fn new(path: &Path, is_dir: bool, size: u64) -> Self {
+        Self {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string()),
+            path: path.display().to_string(),
+            is_dir,
+            size,
+            file_count: if is_dir { 0 } else { 1 },
+            dir_count: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+struct Scanner {
+    max_depth: usize,
+    max_children: usize,
+    top_files: usize,
+    follow_symlinks: bool,
+    token: CancelToken,
+    channel: Channel<ScanEvent>,
+    files: u64,
+    dirs: u64,
+    bytes: u64,
+    last_progress: Instant,
+    largest: BinaryHeap<Reverse<(u64, PathBuf)>>,
+    errors: Vec<ScanError>,
+    error_count: u64,
+    /// 已访问目录的规范路径，用于检测符号链接循环
+    visited: HashSet<PathBuf>,
+    /// 授权根路径，跟随符号链接时目标必须位于其中
+    grants: Vec<String>,
+}
+
+impl Scanner {
+    fn scan_dir(&mut self, path: &Path, depth: usize) -> SizeNode {
+        let mut node = SizeNode::new(path, true, 0);
+        // 跟随符号链接时记录每个实际扫描的目录，指回祖先或已扫描目录的链接不会被重复计算
+        if self.follow_symlinks {
+            match fs::canonicalize(path) {
+                Ok(canonical) => {
+                    if !self.visited.insert(canonical) {
+                        return node;
+                    }
+                }
+                Err(e) => {
+                    self.record_error(path, e);
+                    return node;
+                }
+            }
+        }
+        self.dirs += 1;
+
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) => {
+                self.record_error(path, e);
+                return node;
+            }
+        };
+
+        for entry in entries {
+            if self.token.is_cancelled() {
+                break;
+            }
+
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    self.record_error(path, e);
+                    continue;
+                }
+            };
+            let child_path = entry.path();
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(e) => {
+                    self.record_error(&child_path, e);
+                    continue;
+                }
+            };
+
+            let child = if file_type.is_symlink() {
+                if !self.follow_symlinks {
+                    continue;
+                }
+                let target = match fs::canonicalize(&child_path) {
+                    Ok(target) => target,
+                    Err(e) => {
+                        self.record_error(&child_path, e);
+                        continue;
+                    }
+                };
+                // 不跟随指向授权范围之外的链接
+                if !scoped_fs::is_granted(&self.grants, &target) {
+                    continue;
+                }
+                match fs::metadata(&target) {
+                    Ok(metadata) if metadata.is_dir() => {
+                        // 已访问过的目录，跳过以避免循环
+                        if self.visited.contains(&target) {
+                            continue;
+                        }
+                        self.scan_dir(&child_path, depth + 1)
+                    }
+                    Ok(metadata) => self.scan_file(&child_path, metadata.len()),
+                    Err(e) => {
+                        self.record_error(&child_path, e);
+                        continue;
+                    }
+                }
+            } else if file_type.is_dir() {
+                self.scan_dir(&child_path, depth + 1)
+            } else {
+                match entry.metadata() {
+                    Ok(metadata) => self.scan_file(&child_path, metadata.len()),
+                    Err(e) => {
+                        self.record_error(&child_path, e);
+                        continue;
+                    }
+                }
+            };
+
+            node.size += child.size;
+            node.file_count += child.file_count;
+            node.dir_count += child.dir_count + u64::from(child.is_dir);
+
+            if depth == 0 && child.is_dir && !self.token.is_cancelled() {
+                let _ = self.channel.send(ScanEvent::Entry {
+                    node: child.clone(),
+                });
+            }
+            if depth < self.max_depth {
+                node.children.push(child);
+            }
+
+            self.report_progress(&child_path);
+        }
+
+        node.children.sort_by_key(|child| Reverse(child.size));
+        node.children.truncate(self.max_children);
+        node
+    }
+
+    fn scan_file(&mut self, path: &Path, size: u64) -> SizeNode {
+        self.files += 1;
+        self.bytes += size;
+
+        if self.top_files > 0 {
+            self.largest.push(Reverse((size, path.to_path_buf())));
+            if self.largest.len() > self.top_files {
+                self.largest.pop();
+            }
+        }
+
+        SizeNode::new(path, false, size)
+    }
+
+    fn record_error(&mut self, path: &Path, error: std::io::Error) {
+        self.error_count += 1;
+        if self.errors.len() < MAX_ERRORS {
+            self.errors.push(ScanError {
+                path: path.display().to_string(),
+                message: error.to_string(),
+            });
+        }
+    }
+
+    fn report_progress(&mut self, current: &Path) {
+        if self.last_progress.elapsed() < PROGRESS_INTERVAL {
+            return;
+        }
+        self.last_progress = Instant::now();
+        let _ = self.channel.send(ScanEvent::Progress {
+            files: self.files,
+            dirs: self.dirs,
+            bytes: self.bytes,
+            current: current.display().to_string(),
+        });
+    }
+
+    fn run(mut self, root: &Path) {
+        if let Err(e) = fs::canonicalize(root) {
+            let _ = self.channel.send(ScanEvent::Failed {
+                message: t!("error-read-directory", error = e),
+            });
+            return;
+        }
+
+        let root_node = self.scan_dir(root, 0);
+
+        if self.token.is_cancelled() {
+            let _ = self.channel.send(ScanEvent::Cancelled);
+            return;
+        }
+
+        let largest_files = self
+            .largest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((size, path))| FileEntry {
+                path: path.display().to_string(),
+                size,
+            })
+            .collect();
+
+        let _ = self.channel.send(ScanEvent::Finished {
+            root: root_node,
+            largest_files,
+            errors: self.errors,
+            error_count: self.error_count,
+        });
+    }
+}
+
+/**
+ * 在后台扫描目录大小（目录需位于已授权范围内），通过 Channel 推送进度与结果，返回任务 ID
+ */
+#[tauri::command]
+pub async fn scan_directory_size(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    tasks: State<'_, TaskRegistry>,
+    path: String,
+    options: Option<ScanOptions>,
+    on_event: Channel<ScanEvent>,
+) -> Result<u32, String> {
+    let root = scoped_fs::resolve(&settings, &path)?;
+    if !root.is_dir() {
+        return Err(t!("error-directory-not-found", path = path));
+    }
+
+    let options = options.unwrap_or_default();
+    let (task_id, token) = tasks.register();
+
+    let scanner = Scanner {
+        max_depth: options.max_depth.unwrap_or(3),
+        max_children: options.max_children.unwrap_or(100),
+        top_files: options.top_files.unwrap_or(50),
+        follow_symlinks: options.follow_symlinks.unwrap_or(false),
+        token,
+        channel: on_event,
+        files: 0,
+        dirs: 0,
+        bytes: 0,
+        last_progress: Instant::now(),
+        largest: BinaryHeap::new(),
+        errors: Vec::new(),
+        error_count: 0,
+        visited: HashSet::new(),
+        grants: settings.get().fs_grants,
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        scanner.run(&root);
+        app.state::<TaskRegistry>().finish(task_id);
+    });
+
+    Ok(task_id)
+}
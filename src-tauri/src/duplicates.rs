@@ -0,0 +1,377 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+use crate::tasks::{CancelToken, TaskRegistry};
+
+/// 部分哈希读取的字节数
+const PARTIAL_HASH_BYTES: usize = 16 * 1024;
+/// 进度事件的最小发送间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+// 重复文件查找选项
+#[derive(Debug, Default, Deserialize)]
+pub struct DuplicateOptions {
+    /// 包含的文件匹配模式（如 `**/*.jpg`），为空时包含全部文件
+    pub include: Option<Vec<String>>,
+    /// 排除的文件或目录匹配模式（如 `**/node_modules`）
+    pub exclude: Option<Vec<String>>,
+    /// 最小文件大小（字节，默认 1）
+    pub min_size: Option<u64>,
+}
+
+// 重复文件组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    /// 文件内容的 BLAKE3 哈希
+    hash: String,
+    /// 单个文件大小（字节）
+    size: u64,
+    paths: Vec<String>,
+}
+
+// 查找阶段
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateStage {
+    Scanning,
+    PartialHash,
+    FullHash,
+}
+
+// 重复文件查找事件
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum DuplicateEvent {
+    Progress {
+        stage: DuplicateStage,
+        processed: u64,
+        total: u64,
+    },
+    /// 确认了一组重复文件
+    Group {
+        group: DuplicateGroup,
+    },
+    Finished {
+        group_count: usize,
+        /// 可释放的空间（字节）
+        wasted_bytes: u64,
+    },
+    Cancelled,
+}
+
+struct Finder {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+    min_size: u64,
+    token: CancelToken,
+    channel: Channel<DuplicateEvent>,
+    last_progress: Instant,
+}
+
+impl Finder {
+    fn report_progress(&mut self, stage: DuplicateStage, processed: u64, total: u64) {
+        if self.last_progress.elapsed() < PROGRESS_INTERVAL {
+            return;
+        }
+        self.last_progress = Instant::now();
+        let _ = self.channel.send(DuplicateEvent::Progress {
+            stage,
+            processed,
+            total,
+        });
+    }
+
+    /// 遍历目录，按文件大小分组
+    fn collect_by_size(&mut self, roots: &[PathBuf]) -> HashMap<u64, Vec<PathBuf>> {
+        let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        let mut stack: Vec<PathBuf> = roots.to_vec();
+        // 已记录的文件，防止同一文件被计入多次
+        let mut seen: HashSet<PathBuf> = HashSet::new();
+        let mut scanned = 0;
+
+        while let Some(dir) = stack.pop() {
+            if self.token.is_cancelled() {
+                break;
+            }
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                if self.exclude.as_ref().is_some_and(|set| set.is_match(&path)) {
+                    continue;
+                }
+                // 不跟随符号链接，避免循环与重复计数
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() {
+                    stack.push(path);
+                    continue;
+                }
+                if !file_type.is_file() {
+                    continue;
+                }
+                if self
+                    .include
+                    .as_ref()
+                    .is_some_and(|set| !set.is_match(&path))
+                {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.len() < self.min_size {
+                    continue;
+                }
+
+                if !seen.insert(path.clone()) {
+                    continue;
+                }
+                by_size.entry(metadata.len()).or_default().push(path);
+                scanned += 1;
+                self.report_progress(DuplicateStage::Scanning, scanned, 0);
+            }
+        }
+
+        by_size.retain(|_, paths| paths.len() > 1);
+        by_size
+    }
+
+    /// 在候选组内按哈希再次分组，只保留仍有多个文件的组
+    fn split_by_hash(
+        &mut self,
+        candidates: Vec<(u64, Vec<PathBuf>)>,
+        stage: DuplicateStage,
+    ) -> Vec<(u64, String, Vec<PathBuf>)> {
+        let total = candidates.iter().map(|(_, paths)| paths.len() as u64).sum();
+        let mut processed = 0;
+        let mut result = Vec::new();
+
+        for (size, paths) in candidates {
+            let mut by_hash: HashMap<String, Vec<PathBuf>> = HashMap::new();
+            for path in paths {
+                if self.token.is_cancelled() {
+                    return result;
+                }
+                let limit = match stage {
+                    DuplicateStage::PartialHash => Some(PARTIAL_HASH_BYTES),
+                    _ => None,
+                };
+                // 读取失败（如文件已被删除或无权限）的文件直接忽略
+                if let Ok(hash) = hash_file(&path, limit) {
+                    by_hash.entry(hash).or_default().push(path);
+                }
+                processed += 1;
+                self.report_progress(stage, processed, total);
+            }
+
+            for (hash, paths) in by_hash {
+                if paths.len() < 2 {
+                    continue;
+                }
+                if let DuplicateStage::FullHash = stage {
+                    let group = DuplicateGroup {
+                        hash: hash.clone(),
+                        size,
+                        paths: paths.iter().map(|p| p.display().to_string()).collect(),
+                    };
+                    let _ = self.channel.send(DuplicateEvent::Group { group });
+                }
+                result.push((size, hash, paths));
+            }
+        }
+
+        result
+    }
+
+    fn run(mut self, roots: Vec<PathBuf>) {
+        let by_size = self.collect_by_size(&roots);
+
+        // 先按部分哈希筛选，再对剩余文件计算完整哈希
+        let partial =
+            self.split_by_hash(by_size.into_iter().collect(), DuplicateStage::PartialHash);
+        let candidates = partial
+            .into_iter()
+            .map(|(size, _, paths)| (size, paths))
+            .collect();
+        let groups = self.split_by_hash(candidates, DuplicateStage::FullHash);
+
+        if self.token.is_cancelled() {
+            let _ = self.channel.send(DuplicateEvent::Cancelled);
+            return;
+        }
+
+        let wasted_bytes = groups
+            .iter()
+            .map(|(size, _, paths)| size * (paths.len() as u64 - 1))
+            .sum();
+        let _ = self.channel.send(DuplicateEvent::Finished {
+            group_count: groups.len(),
+            wasted_bytes,
+        });
+    }
+}
+
+/**
+ * 计算文件的 BLAKE3 哈希，`limit` 为 Some 时只读取开头部分
+ */
+fn hash_file(path: &Path, limit: Option<usize>) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = blake3::Hasher::new();
+
+    match limit {
+        Some(limit) => {
+            let mut buffer = Vec::with_capacity(limit);
+            file.take(limit as u64).read_to_end(&mut buffer)?;
+            hasher.update(&buffer);
+        }
+        None => {
+            hasher.update_reader(&mut file)?;
+        }
+    }
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/**
+ * 构建文件匹配集合
+ */
+fn build_glob_set(patterns: Option<Vec<String>>) -> Result<Option<GlobSet>, String> {
+    let Some(patterns) = patterns.filter(|patterns| !patterns.is_empty()) else {
+        return Ok(None);
+    };
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &patterns {
+        let glob = Glob::new(pattern)
+            .map_err(|e| t!("error-invalid-pattern", pattern = pattern, error = e))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map(Some)
+        .map_err(|e| t!("error-build-pattern", error = e))
+}
+
+/**
+ * 转义 CSV 字段
+ */
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/**
+ * 规范化查找目录：解析符号链接并检查授权范围，去掉重复目录以及已包含在其他目录中的子目录，
+ * 避免重叠的目录把同一文件当作自身的重复项
+ */
+fn normalize_roots(settings: &SettingsState, paths: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut roots = Vec::new();
+    for path in paths {
+        let root = scoped_fs::resolve(settings, path)?;
+        if !root.is_dir() {
+            return Err(t!("error-directory-not-found", path = path));
+        }
+        roots.push(root);
+    }
+
+    // 排序后父目录排在其子目录之前
+    roots.sort();
+    roots.dedup();
+    let mut result: Vec<PathBuf> = Vec::new();
+    for root in roots {
+        if !result.iter().any(|parent| root.starts_with(parent)) {
+            result.push(root);
+        }
+    }
+    Ok(result)
+}
+
+/**
+ * 在后台查找重复文件（目录需位于已授权范围内），通过 Channel 推送确认的重复组，返回任务 ID
+ */
+#[tauri::command]
+pub async fn find_duplicates(
+    app: AppHandle,
+    tasks: State<'_, TaskRegistry>,
+    settings: State<'_, SettingsState>,
+    paths: Vec<String>,
+    options: Option<DuplicateOptions>,
+    on_event: Channel<DuplicateEvent>,
+) -> Result<u32, String> {
+    let roots = normalize_roots(&settings, &paths)?;
+
+    let options = options.unwrap_or_default();
+    let include = build_glob_set(options.include)?;
+    let exclude = build_glob_set(options.exclude)?;
+    let (task_id, token) = tasks.register();
+
+    let finder = Finder {
+        include,
+        exclude,
+        min_size: options.min_size.unwrap_or(1),
+        token,
+        channel: on_event,
+        last_progress: Instant::now(),
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        finder.run(roots);
+        app.state::<TaskRegistry>().finish(task_id);
+    });
+
+    Ok(task_id)
+}
+
+/**
+ * 导出重复文件查找结果（format 为 json 或 csv，路径需位于已授权范围内）
+ */
+#[tauri::command]
+pub async fn export_duplicates(
+    settings: State<'_, SettingsState>,
+    groups: Vec<DuplicateGroup>,
+    path: String,
+    format: String,
+) -> Result<(), String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+    let content = match format.to_lowercase().as_str() {
+        "json" => {
+            serde_json::to_string_pretty(&groups).map_err(|e| t!("error-serialize", error = e))?
+        }
+        "csv" => {
+            let mut content = String::from("group,hash,size,path\n");
+            for (index, group) in groups.iter().enumerate() {
+                for path in &group.paths {
+                    content.push_str(&format!(
+                        "{},{},{},{}\n",
+                        index + 1,
+                        group.hash,
+                        group.size,
+                        csv_field(path)
+                    ));
+                }
+            }
+            content
+        }
+        _ => return Err(t!("error-unsupported-export-format", format = format)),
+    };
+
+    fs::write(&path, content).map_err(|e| t!("error-write-file", error = e))
+}
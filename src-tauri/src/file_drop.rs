@@ -0,0 +1,215 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, State, Window};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+
+/// 文件拖放到窗口后发送给该窗口的事件名
+pub const FILES_DROPPED_EVENT: &str = "files-dropped";
+
+// 拖放接受规则（保存在设置中）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DropRules {
+    /// 接受的文件扩展名（不含点，不区分大小写），为空时接受全部文件
+    pub extensions: Vec<String>,
+    /// 单个文件的最大大小（字节）
+    pub max_size: Option<u64>,
+    /// 一次最多接受的条目数
+    pub max_count: Option<usize>,
+    /// 是否接受目录
+    pub allow_directories: bool,
+    /// 是否将接受的条目授权给文件系统 API
+    pub grant: bool,
+}
+
+impl Default for DropRules {
+    fn default() -> Self {
+        Self {
+            extensions: Vec::new(),
+            max_size: None,
+            max_count: None,
+            allow_directories: true,
+            grant: false,
+        }
+    }
+}
+
+// 拖放条目类型
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DroppedKind {
+    File,
+    Directory,
+    Symlink,
+}
+
+// 被拒绝的原因
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectReason {
+    /// 路径不存在或无法访问
+    NotFound,
+    DirectoryNotAllowed,
+    ExtensionNotAllowed,
+    TooLarge,
+    TooMany,
+    /// 既不是普通文件也不是目录（如设备文件）
+    Unsupported,
+}
+
+// 接受的条目
+#[derive(Debug, Clone, Serialize)]
+pub struct DroppedFile {
+    /// 规范化后的路径（符号链接为其目标路径）
+    path: String,
+    kind: DroppedKind,
+    /// 符号链接本身的路径
+    link_path: Option<String>,
+    /// 文件大小（字节），目录为 0
+    size: u64,
+}
+
+// 被拒绝的条目
+#[derive(Debug, Clone, Serialize)]
+pub struct RejectedFile {
+    path: String,
+    reason: RejectReason,
+}
+
+// 文件拖放事件
+#[derive(Debug, Clone, Serialize)]
+pub struct FilesDroppedEvent {
+    accepted: Vec<DroppedFile>,
+    rejected: Vec<RejectedFile>,
+    /// 放下位置（窗口内的物理像素坐标）
+    x: f64,
+    y: f64,
+}
+
+/**
+ * 按规则检查单个拖放条目
+ */
+fn classify(path: &Path, rules: &DropRules) -> Result<DroppedFile, RejectReason> {
+    let link_metadata = fs::symlink_metadata(path).map_err(|_| RejectReason::NotFound)?;
+    let is_symlink = link_metadata.file_type().is_symlink();
+    let canonical = path.canonicalize().map_err(|_| RejectReason::NotFound)?;
+    let metadata = fs::metadata(&canonical).map_err(|_| RejectReason::NotFound)?;
+
+    if metadata.is_dir() {
+        if !rules.allow_directories {
+            return Err(RejectReason::DirectoryNotAllowed);
+        }
+    } else if metadata.is_file() {
+        if !rules.extensions.is_empty() {
+            let extension = canonical
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            let allowed = rules
+                .extensions
+                .iter()
+                .any(|allowed| allowed.trim_start_matches('.').to_lowercase() == extension);
+            if !allowed {
+                return Err(RejectReason::ExtensionNotAllowed);
+            }
+        }
+        if rules.max_size.is_some_and(|max| metadata.len() > max) {
+            return Err(RejectReason::TooLarge);
+        }
+    } else {
+        return Err(RejectReason::Unsupported);
+    }
+
+    let kind = if is_symlink {
+        DroppedKind::Symlink
+    } else if metadata.is_dir() {
+        DroppedKind::Directory
+    } else {
+        DroppedKind::File
+    };
+    Ok(DroppedFile {
+        path: canonical.display().to_string(),
+        kind,
+        link_path: is_symlink.then(|| path.display().to_string()),
+        size: if metadata.is_file() {
+            metadata.len()
+        } else {
+            0
+        },
+    })
+}
+
+/**
+ * 处理拖放到窗口的文件：按规则分类、可选授权，并向该窗口发送 `files-dropped` 事件
+ */
+pub fn handle_drop(window: &Window, paths: Vec<PathBuf>, position: PhysicalPosition<f64>) {
+    let app = window.app_handle().clone();
+    let label = window.label().to_string();
+
+    // 在后台线程访问文件系统，避免阻塞事件循环
+    tauri::async_runtime::spawn_blocking(move || {
+        let rules = app.state::<SettingsState>().get().file_drop;
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+
+        for path in paths {
+            let result = match classify(&path, &rules) {
+                Ok(_) if rules.max_count.is_some_and(|max| accepted.len() >= max) => {
+                    Err(RejectReason::TooMany)
+                }
+                result => result,
+            };
+            match result {
+                Ok(file) => accepted.push(file),
+                Err(reason) => rejected.push(RejectedFile {
+                    path: path.display().to_string(),
+                    reason,
+                }),
+            }
+        }
+
+        if rules.grant {
+            for file in &accepted {
+                scoped_fs::grant(&app, Path::new(&file.path));
+            }
+        }
+
+        let event = FilesDroppedEvent {
+            accepted,
+            rejected,
+            x: position.x,
+            y: position.y,
+        };
+        if let Err(e) = app.emit_to(label.as_str(), FILES_DROPPED_EVENT, event) {
+            log::warn!("Failed to emit dropped files: {e}");
+        }
+    });
+}
+
+/**
+ * 获取文件拖放的接受规则
+ */
+#[tauri::command]
+pub async fn get_drop_rules(settings: State<'_, SettingsState>) -> Result<DropRules, String> {
+    Ok(settings.get().file_drop)
+}
+
+/**
+ * 设置文件拖放的接受规则
+ */
+#[tauri::command]
+pub async fn set_drop_rules(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    rules: DropRules,
+) -> Result<(), String> {
+    if rules.max_count == Some(0) {
+        return Err(t!("error-invalid-drop-rules"));
+    }
+    settings.update(&app, |settings| settings.file_drop = rules)
+}
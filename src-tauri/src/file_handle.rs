@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use serde::Serialize;
+use tauri::ipc::Response;
+use tauri::{AppHandle, EventId, Listener, Manager, State, WebviewWindow};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+use crate::tasks::CancelToken;
+
+/// 单次读取的最大字节数
+const MAX_READ_BYTES: u64 = 16 * 1024 * 1024;
+/// 单次读取的最大行数
+const MAX_READ_LINES: u64 = 100_000;
+/// 行索引的间隔：每隔这么多行记录一次起始偏移，读取时从最近的记录点向后扫描
+const LINE_INDEX_STRIDE: u64 = 1000;
+/// 建立行索引时的读取块大小
+const INDEX_CHUNK_BYTES: usize = 1024 * 1024;
+
+// 稀疏行索引（后台建立）
+#[derive(Debug, Default)]
+struct LineIndex {
+    /// 第 `k * LINE_INDEX_STRIDE` 行的起始偏移
+    checkpoints: Vec<u64>,
+    /// 已扫描到的行数
+    line_count: u64,
+    /// 是否已扫描完整个文件
+    complete: bool,
+}
+
+struct FileHandle {
+    path: PathBuf,
+    size: u64,
+    file: Mutex<File>,
+    index: Arc<RwLock<LineIndex>>,
+    /// 关闭时停止建立行索引
+    token: CancelToken,
+    window: String,
+    /// 窗口销毁时自动关闭句柄的事件监听器
+    listener: EventId,
+}
+
+// 文件句柄信息
+#[derive(Debug, Serialize)]
+pub struct FileHandleInfo {
+    handle_id: u32,
+    path: String,
+    size: u64,
+    /// 当前已索引的行数，索引未完成时会继续增长
+    line_count: u64,
+    line_index_complete: bool,
+}
+
+impl FileHandle {
+    fn info(&self, handle_id: u32) -> FileHandleInfo {
+        let index = self.index.read().unwrap();
+        FileHandleInfo {
+            handle_id,
+            path: self.path.display().to_string(),
+            size: self.size,
+            line_count: index.line_count,
+            line_index_complete: index.complete,
+        }
+    }
+
+    /// 读取 `[offset, offset + length)` 字节范围（阻塞，需在后台线程调用）
+    fn read_range(&self, offset: u64, length: u64) -> Result<Vec<u8>, String> {
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| t!("error-fs-io", error = e))?;
+        let mut buffer = Vec::new();
+        (&mut *file)
+            .take(length)
+            .read_to_end(&mut buffer)
+            .map_err(|e| t!("error-fs-io", error = e))?;
+        Ok(buffer)
+    }
+
+    /// 读取从第 `start_line` 行起的 `count` 行原始字节（阻塞，需在后台线程调用）
+    fn read_lines(&self, start_line: u64, count: u64) -> Result<Vec<u8>, String> {
+        let (mut line, offset) = {
+            let index = self.index.read().unwrap();
+            let checkpoint = (start_line / LINE_INDEX_STRIDE)
+                .min(index.checkpoints.len().saturating_sub(1) as u64);
+            let offset = index
+                .checkpoints
+                .get(checkpoint as usize)
+                .copied()
+                .unwrap_or(0);
+            (checkpoint * LINE_INDEX_STRIDE, offset)
+        };
+
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| t!("error-fs-io", error = e))?;
+        let mut reader = BufReader::new(&mut *file);
+
+        // 单行可能非常长（如二进制文件），跳过时不缓存内容，读取时总量不超过上限
+        let mut buffer = Vec::new();
+        while line < start_line.saturating_add(count) {
+            let read = if line < start_line {
+                reader.skip_until(b'\n')
+            } else {
+                let remaining = MAX_READ_BYTES - buffer.len() as u64;
+                (&mut reader).take(remaining).read_until(b'\n', &mut buffer)
+            }
+            .map_err(|e| t!("error-fs-io", error = e))?;
+            if read == 0 || buffer.len() as u64 >= MAX_READ_BYTES {
+                break;
+            }
+            line += 1;
+        }
+        Ok(buffer)
+    }
+}
+
+/// 文件句柄状态（托管在 Tauri State 中）
+pub struct FileHandleState {
+    next_id: AtomicU32,
+    handles: Mutex<HashMap<u32, Arc<FileHandle>>>,
+}
+
+impl FileHandleState {
+    pub fn new() -> Self {
+        Self {
+            next_id: AtomicU32::new(1),
+            handles: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, handle_id: u32) -> Result<Arc<FileHandle>, String> {
+        self.handles
+            .lock()
+            .unwrap()
+            .get(&handle_id)
+            .cloned()
+            .ok_or_else(|| t!("error-file-handle-not-found", id = handle_id))
+    }
+
+    fn remove(&self, handle_id: u32) -> Option<Arc<FileHandle>> {
+        let handle = self.handles.lock().unwrap().remove(&handle_id)?;
+        handle.token.cancel();
+        Some(handle)
+    }
+}
+
+impl Default for FileHandleState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**
+ * 在后台扫描文件，每隔 `LINE_INDEX_STRIDE` 行记录一次行起始偏移
+ */
+fn build_line_index(path: &Path, index: &RwLock<LineIndex>, token: &CancelToken) {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            log::warn!("Failed to index lines of {}: {e}", path.display());
+            return;
+        }
+    };
+
+    let mut buffer = vec![0; INDEX_CHUNK_BYTES];
+    let mut offset = 0;
+    let mut line_count = 0;
+    let mut last_byte = None;
+    index.write().unwrap().checkpoints.push(0);
+
+    loop {
+        if token.is_cancelled() {
+            return;
+        }
+        let read = match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) => {
+                log::warn!("Failed to index lines of {}: {e}", path.display());
+                return;
+            }
+        };
+
+        let mut checkpoints = Vec::new();
+        for (i, _) in buffer[..read]
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+        {
+            line_count += 1;
+            if line_count % LINE_INDEX_STRIDE == 0 {
+                checkpoints.push(offset + i as u64 + 1);
+            }
+        }
+        offset += read as u64;
+        last_byte = buffer.get(read - 1).copied();
+
+        let mut index = index.write().unwrap();
+        index.checkpoints.extend(checkpoints);
+        index.line_count = line_count;
+    }
+
+    // 最后一行没有换行符时也计为一行
+    let mut index = index.write().unwrap();
+    if last_byte.is_some_and(|b| b != b'\n') {
+        index.line_count += 1;
+    }
+    index.complete = true;
+}
+
+/**
+ * 打开文件句柄（路径需位于已授权范围内），返回句柄信息并在后台建立行索引
+ *
+ * 调用窗口关闭时自动关闭句柄
+ */
+#[tauri::command]
+pub async fn open_file_handle(
+    app: AppHandle,
+    window: WebviewWindow,
+    settings: State<'_, SettingsState>,
+    handles: State<'_, FileHandleState>,
+    path: String,
+) -> Result<FileHandleInfo, String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+    let file = File::open(&path).map_err(|e| t!("error-fs-io", error = e))?;
+    let metadata = file.metadata().map_err(|e| t!("error-fs-io", error = e))?;
+    if !metadata.is_file() {
+        return Err(t!(
+            "error-fs-invalid-path",
+            path = path.display().to_string()
+        ));
+    }
+
+    let id = handles.next_id.fetch_add(1, Ordering::Relaxed);
+    let index = Arc::new(RwLock::new(LineIndex::default()));
+    let token = CancelToken::default();
+
+    let cleanup_app = app.clone();
+    let listener = window.once("tauri://destroyed", move |_| {
+        cleanup_app.state::<FileHandleState>().remove(id);
+    });
+
+    let handle = Arc::new(FileHandle {
+        path: path.clone(),
+        size: metadata.len(),
+        file: Mutex::new(file),
+        index: index.clone(),
+        token: token.clone(),
+        window: window.label().to_string(),
+        listener,
+    });
+    let info = handle.info(id);
+    handles.handles.lock().unwrap().insert(id, handle);
+
+    tauri::async_runtime::spawn_blocking(move || build_line_index(&path, &index, &token));
+
+    Ok(info)
+}
+
+/**
+ * 获取文件句柄信息（可用于查询行索引进度）
+ */
+#[tauri::command]
+pub async fn get_file_handle_info(
+    handles: State<'_, FileHandleState>,
+    handle_id: u32,
+) -> Result<FileHandleInfo, String> {
+    Ok(handles.get(handle_id)?.info(handle_id))
+}
+
+/**
+ * 读取 `[offset, offset + length)` 字节范围，以二进制返回（超出文件末尾的部分被截断）
+ */
+#[tauri::command]
+pub async fn read_file_range(
+    handles: State<'_, FileHandleState>,
+    handle_id: u32,
+    offset: u64,
+    length: u64,
+) -> Result<Response, String> {
+    if length > MAX_READ_BYTES {
+        return Err(t!("error-read-range-too-large", max = MAX_READ_BYTES));
+    }
+    let handle = handles.get(handle_id)?;
+
+    tauri::async_runtime::spawn_blocking(move || handle.read_range(offset, length))
+        .await
+        .map_err(|e| t!("error-fs-io", error = e))?
+        .map(Response::new)
+}
+
+/**
+ * 读取从第 `start_line` 行（从 0 开始）起的 `count` 行，以二进制返回原始字节（包括换行符）
+ *
+ * 从行索引中最近的记录点向后扫描，索引未完成时也可以读取，只是更慢
+ */
+#[tauri::command]
+pub async fn read_file_lines(
+    handles: State<'_, FileHandleState>,
+    handle_id: u32,
+    start_line: u64,
+    count: u64,
+) -> Result<Response, String> {
+    if count > MAX_READ_LINES {
+        return Err(t!("error-read-range-too-large", max = MAX_READ_LINES));
+    }
+    let handle = handles.get(handle_id)?;
+
+    tauri::async_runtime::spawn_blocking(move || handle.read_lines(start_line, count))
+        .await
+        .map_err(|e| t!("error-fs-io", error = e))?
+        .map(Response::new)
+}
+
+/**
+ * 关闭文件句柄
+ */
+#[tauri::command]
+pub async fn close_file_handle(
+    app: AppHandle,
+    handles: State<'_, FileHandleState>,
+    handle_id: u32,
+) -> Result<(), String> {
+    let handle = handles
+        .remove(handle_id)
+        .ok_or_else(|| t!("error-file-handle-not-found", id = handle_id))?;
+
+    if let Some(window) = app.get_webview_window(&handle.window) {
+        window.unlisten(handle.listener);
+    }
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify_debouncer_full::notify::event::{ModifyKind, RenameMode};
+use notify_debouncer_full::notify::{EventKind, RecommendedWatcher, RecursiveMode};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, RecommendedCache};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, EventId, Listener, Manager, State, WebviewWindow};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+
+/// 文件变化时发送给订阅窗口的事件名
+pub const FILE_CHANGE_EVENT: &str = "file-change";
+/// 默认防抖时间（毫秒）
+const DEFAULT_DEBOUNCE_MS: u64 = 300;
+
+// 单个文件变化
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FileChange {
+    Created { path: String },
+    Modified { path: String },
+    Removed { path: String },
+    Renamed { from: String, to: String },
+}
+
+// 文件变化事件（同一防抖周期内的变化合并发送）
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChangeEvent {
+    watch_id: u32,
+    changes: Vec<FileChange>,
+}
+
+struct Watch {
+    window: String,
+    /// 窗口销毁时自动取消监听的事件监听器
+    listener: EventId,
+    /// 丢弃时停止监听
+    _debouncer: Debouncer<RecommendedWatcher, RecommendedCache>,
+}
+
+/// 文件监听状态（托管在 Tauri State 中）
+pub struct WatcherState {
+    next_id: AtomicU32,
+    watches: Mutex<HashMap<u32, Watch>>,
+}
+
+impl WatcherState {
+    pub fn new() -> Self {
+        Self {
+            next_id: AtomicU32::new(1),
+            watches: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for WatcherState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**
+ * 将防抖后的事件转换为文件变化列表
+ */
+fn to_changes(result: DebounceEventResult) -> Vec<FileChange> {
+    let Ok(events) = result else {
+        return Vec::new();
+    };
+
+    events
+        .into_iter()
+        .filter_map(|event| {
+            let path = |index: usize| {
+                event
+                    .paths
+                    .get(index)
+                    .map(|path| path.display().to_string())
+            };
+
+            let change = match event.kind {
+                EventKind::Create(_) => FileChange::Created { path: path(0)? },
+                EventKind::Remove(_) => FileChange::Removed { path: path(0)? },
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => FileChange::Renamed {
+                    from: path(0)?,
+                    to: path(1)?,
+                },
+                EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+                    FileChange::Removed { path: path(0)? }
+                }
+                EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                    FileChange::Created { path: path(0)? }
+                }
+                // 无法区分重命名的两端时，按路径是否仍存在判断
+                EventKind::Modify(ModifyKind::Name(_)) => {
+                    if event.paths.first()?.exists() {
+                        FileChange::Created { path: path(0)? }
+                    } else {
+                        FileChange::Removed { path: path(0)? }
+                    }
+                }
+                EventKind::Modify(_) => FileChange::Modified { path: path(0)? },
+                _ => return None,
+            };
+            Some(change)
+        })
+        .collect()
+}
+
+/**
+ * 监听文件或目录的变化（路径需位于已授权范围内），变化通过事件发送给调用窗口，返回监听 ID
+ *
+ * 调用窗口关闭时自动取消监听
+ */
+#[tauri::command]
+pub async fn watch_path(
+    app: AppHandle,
+    window: WebviewWindow,
+    settings: State<'_, SettingsState>,
+    watcher: State<'_, WatcherState>,
+    path: String,
+    recursive: Option<bool>,
+    debounce_ms: Option<u64>,
+) -> Result<u32, String> {
+    let target = scoped_fs::resolve(&settings, &path)?;
+    let id = watcher.next_id.fetch_add(1, Ordering::Relaxed);
+    let label = window.label().to_string();
+
+    let event_app = app.clone();
+    let event_label = label.clone();
+    let mut debouncer = new_debouncer(
+        Duration::from_millis(debounce_ms.unwrap_or(DEFAULT_DEBOUNCE_MS)),
+        None,
+        move |result: DebounceEventResult| {
+            let changes = to_changes(result);
+            if changes.is_empty() {
+                return;
+            }
+            let event = FileChangeEvent {
+                watch_id: id,
+                changes,
+            };
+            if let Err(e) = event_app.emit_to(event_label.as_str(), FILE_CHANGE_EVENT, event) {
+                log::warn!("Failed to emit file change: {e}");
+            }
+        },
+    )
+    .map_err(|e| t!("error-watch-path", error = e))?;
+
+    let mode = if recursive.unwrap_or(false) {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    debouncer
+        .watch(&target, mode)
+        .map_err(|e| t!("error-watch-path", error = e))?;
+
+    let cleanup_app = app.clone();
+    let listener = window.once("tauri://destroyed", move |_| {
+        cleanup_app
+            .state::<WatcherState>()
+            .watches
+            .lock()
+            .unwrap()
+            .remove(&id);
+    });
+
+    watcher.watches.lock().unwrap().insert(
+        id,
+        Watch {
+            window: label,
+            listener,
+            _debouncer: debouncer,
+        },
+    );
+
+    Ok(id)
+}
+
+/**
+ * 取消文件监听
+ */
+#[tauri::command]
+pub async fn unwatch_path(
+    app: AppHandle,
+    watcher: State<'_, WatcherState>,
+    watch_id: u32,
+) -> Result<(), String> {
+    let watch = watcher
+        .watches
+        .lock()
+        .unwrap()
+        .remove(&watch_id)
+        .ok_or_else(|| t!("error-watch-not-found", id = watch_id))?;
+
+    if let Some(window) = app.get_webview_window(&watch.window) {
+        window.unlisten(watch.listener);
+    }
+    Ok(())
+}
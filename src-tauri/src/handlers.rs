@@ -1,17 +1,18 @@
 use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::process::Command;
-use tauri::AppHandle;
-use tauri_plugin_dialog::DialogExt;
-
-// 系统信息结构体
-#[derive(Debug, Serialize)]
-pub struct SystemInfo {
-    key: String,
-    name: String,
-    value: serde_json::Value,
-}
+use std::path::Path;
+use tauri::{AppHandle, Listener, State, WebviewWindow};
+use tauri_plugin_dialog::{DialogExt, FileDialogBuilder, FilePath};
+use tokio::sync::oneshot;
+
+use crate::build_info::BuildInfo;
+use crate::diagnostics::HttpErrorLog;
+use crate::i18n::t;
+use crate::monitor::MonitorState;
+use crate::recent::{self, RecentKind};
+use crate::scoped_fs;
+use crate::system_info::{SystemInfo, SystemInfoRegistry};
 
 // 版本信息结构体
 #[derive(Debug, Serialize)]
@@ -19,21 +20,86 @@ pub struct VersionInfo {
     app_version: String,
     rust_version: String,
     os_version: String,
+    build: BuildInfo,
+}
+
+// 主机信息结构体
+#[derive(Debug, Serialize)]
+pub struct HostInfo {
+    /// 操作系统名称，如 `Ubuntu`、`Darwin`、`Windows`
+    os_name: Option<String>,
+    os_version: Option<String>,
+    /// 完整的系统版本描述，如 `Linux (Ubuntu 24.04)`
+    long_os_version: Option<String>,
+    /// 系统代号，如 `Sonoma`（目前只有 macOS 提供，sysinfo 不解析 Linux 发行版代号）
+    os_codename: Option<String>,
+    /// 发行版标识，如 `ubuntu`、`macos`、`windows`
+    distribution_id: String,
+    kernel_version: Option<String>,
+    hostname: Option<String>,
+    arch: String,
+    /// 运行时长（秒）
+    uptime: u64,
+    /// 启动时间（Unix 秒）
+    boot_time: u64,
+    current_user: Option<String>,
+    /// 系统语言（BCP 47），如 `zh-CN`
+    locale: Option<String>,
+    /// IANA 时区，如 `Asia/Shanghai`
+    timezone: Option<String>,
 }
 
 // 网络信息结构体
 #[derive(Debug, Serialize)]
 pub struct NetworkInfo {
-    local_ip: String,
-    public_ip: String,
+    local_ip: Option<String>,
+    mac_address: Option<String>,
+    /// 公网 IP（需要访问外部服务，未请求或失败时为空）
+    public_ip: Option<String>,
 }
 
-// 硬盘信息结构体
+// 硬盘类型
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiskKind {
+    Ssd,
+    Hdd,
+    Removable,
+    Unknown,
+}
+
+// 硬盘信息结构体（容量单位均为字节，格式化由调用方处理）
 #[derive(Debug, Serialize)]
 pub struct DiskInfo {
-    total: String,
-    used: String,
-    free: String,
+    name: String,
+    mount_point: String,
+    file_system: String,
+    kind: DiskKind,
+    total: u64,
+    used: u64,
+    free: u64,
+    is_read_only: bool,
+}
+
+// 文件对话框过滤器
+#[derive(Debug, Deserialize)]
+pub struct DialogFilter {
+    pub name: String,
+    /// 扩展名（不含点），如 `["png", "jpg"]`
+    pub extensions: Vec<String>,
+}
+
+// 文件对话框选项
+// 不支持“显示隐藏文件”：tauri-plugin-dialog 2.7 没有提供对应的 API，是否显示由系统对话框决定
+#[derive(Debug, Default, Deserialize)]
+pub struct FileDialogOptions {
+    pub title: Option<String>,
+    /// 默认目录，或默认文件的完整路径
+    pub default_path: Option<String>,
+    /// 默认文件名
+    pub file_name: Option<String>,
+    /// 文件过滤器，打开文件时为空则使用“所有文件”
+    pub filters: Option<Vec<DialogFilter>>,
 }
 
 // HTTP 请求配置
@@ -66,42 +132,14 @@ pub struct HttpResponse {
 }
 
 /**
- * 获取系统信息
+ * 获取系统信息（汇总所有已注册提供者的信息行）
  */
 #[tauri::command]
-pub async fn get_system_info() -> Result<Vec<SystemInfo>, String> {
-    let arch = std::env::consts::ARCH.to_string();
-    let platform = std::env::consts::OS.to_string();
-    let cpu_count = num_cpus::get();
-
-    // 获取总内存（字节）
-    let total_memory = sysinfo::System::new_all().total_memory();
-    let total_memory_gb = (total_memory as f64 / 1024.0 / 1024.0 / 1024.0).round() as u64;
-
-    let system_info = vec![
-        SystemInfo {
-            key: "arch".to_string(),
-            name: "系统架构".to_string(),
-            value: serde_json::Value::String(arch),
-        },
-        SystemInfo {
-            key: "platform".to_string(),
-            name: "操作系统".to_string(),
-            value: serde_json::Value::String(platform),
-        },
-        SystemInfo {
-            key: "cpu_count".to_string(),
-            name: "CPU 核心数".to_string(),
-            value: serde_json::Value::Number(serde_json::Number::from(cpu_count)),
-        },
-        SystemInfo {
-            key: "total_memory".to_string(),
-            name: "总内存".to_string(),
-            value: serde_json::Value::String(format!("{total_memory_gb} GB")),
-        },
-    ];
-
-    Ok(system_info)
+pub async fn get_system_info(
+    app: AppHandle,
+    registry: State<'_, SystemInfoRegistry>,
+) -> Result<Vec<SystemInfo>, String> {
+    Ok(registry.collect(&app).await)
 }
 
 /**
@@ -113,172 +151,372 @@ pub async fn get_version_info() -> Result<VersionInfo, String> {
     let rust_version = rustc_version_runtime::version().to_string();
 
     // 获取操作系统版本
-    let os_version = match std::env::consts::OS {
-        "windows" => {
-            // Windows 版本获取
-            match Command::new("cmd").args(["/C", "ver"]).output() {
-                Ok(output) => String::from_utf8_lossy(&output.stdout).trim().to_string(),
-                Err(_) => "Unknown Windows Version".to_string(),
-            }
-        }
-        "macos" => {
-            // macOS 版本获取
-            match Command::new("sw_vers").arg("-productVersion").output() {
-                Ok(output) => String::from_utf8_lossy(&output.stdout).trim().to_string(),
-                Err(_) => "Unknown macOS Version".to_string(),
-            }
-        }
-        "linux" => {
-            // Linux 版本获取
-            match std::fs::read_to_string("/etc/os-release") {
-                Ok(content) => {
-                    for line in content.lines() {
-                        if line.starts_with("PRETTY_NAME=") {
-                            return Ok(VersionInfo {
-                                app_version,
-                                rust_version,
-                                os_version: line
-                                    .split('=')
-                                    .nth(1)
-                                    .unwrap_or("Unknown Linux")
-                                    .trim_matches('"')
-                                    .to_string(),
-                            });
-                        }
-                    }
-                    "Unknown Linux Distribution".to_string()
-                }
-                Err(_) => "Unknown Linux Version".to_string(),
-            }
-        }
-        _ => "Unknown OS".to_string(),
-    };
+    let os_version =
+        sysinfo::System::long_os_version().unwrap_or_else(|| t!("system-info-unknown-os"));
 
     Ok(VersionInfo {
         app_version,
         rust_version,
         os_version,
+        build: BuildInfo::current(),
     })
 }
 
 /**
- * 获取硬盘信息
+ * 获取主机信息
  */
 #[tauri::command]
-pub async fn get_disk_info() -> Result<DiskInfo, String> {
-    let output = match std::env::consts::OS {
-        "windows" => Command::new("wmic")
-            .args(["logicaldisk", "get", "size,freespace,caption"])
-            .output(),
-        "macos" | "linux" => Command::new("df").args(["-h", "/"]).output(),
-        _ => return Err("不支持的操作系统".to_string()),
-    };
+pub async fn get_host_info(monitor: State<'_, MonitorState>) -> Result<HostInfo, String> {
+    use sysinfo::System;
+
+    Ok(HostInfo {
+        os_name: System::name(),
+        os_version: System::os_version(),
+        long_os_version: System::long_os_version(),
+        os_codename: os_codename(),
+        distribution_id: System::distribution_id(),
+        kernel_version: System::kernel_version(),
+        hostname: System::host_name(),
+        arch: System::cpu_arch(),
+        uptime: System::uptime(),
+        boot_time: System::boot_time(),
+        current_user: current_user(&monitor),
+        locale: sys_locale::get_locale(),
+        timezone: iana_time_zone::get_timezone().ok(),
+    })
+}
 
-    match output {
-        Ok(output) => {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            parse_disk_info(&stdout)
-        }
-        Err(e) => Err(format!("获取硬盘信息失败: {e}")),
+/**
+ * 获取系统代号（macOS 按 sysinfo 报告的主版本号对应，其他系统返回 None）
+ */
+fn os_codename() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        let major: u32 = sysinfo::System::os_version()?
+            .split('.')
+            .next()?
+            .parse()
+            .ok()?;
+        let codename = match major {
+            11 => "Big Sur",
+            12 => "Monterey",
+            13 => "Ventura",
+            14 => "Sonoma",
+            15 => "Sequoia",
+            26 => "Tahoe",
+            _ => return None,
+        };
+        Some(codename.to_string())
     }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        None
+    }
+}
+
+/**
+ * 获取运行应用的当前用户名
+ */
+fn current_user(monitor: &MonitorState) -> Option<String> {
+    use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, UpdateKind, Users};
+
+    let pid = sysinfo::get_current_pid().ok()?;
+    let uid = monitor.with_system(|shared| {
+        shared.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_user(UpdateKind::OnlyIfNotSet),
+        );
+        shared.system.process(pid)?.user_id().cloned()
+    })?;
+
+    Users::new_with_refreshed_list()
+        .get_user_by_id(&uid)
+        .map(|user| user.name().to_string())
 }
 
 /**
- * 解析硬盘信息的辅助函数
+ * 获取硬盘信息（所有已挂载的卷）
  */
-fn parse_disk_info(output: &str) -> Result<DiskInfo, String> {
-    let lines: Vec<&str> = output.lines().collect();
-
-    if std::env::consts::OS == "windows" {
-        if lines.len() >= 2 {
-            let parts: Vec<&str> = lines[1].split_whitespace().collect();
-            if parts.len() >= 2 {
-                let total_bytes: u64 = parts[1].parse().unwrap_or(0);
-                let free_bytes: u64 = parts[0].parse().unwrap_or(0);
-                let used_bytes = total_bytes.saturating_sub(free_bytes);
-
-                return Ok(DiskInfo {
-                    total: format!("{}GB", total_bytes / 1024 / 1024 / 1024),
-                    free: format!("{}GB", free_bytes / 1024 / 1024 / 1024),
-                    used: format!("{}GB", used_bytes / 1024 / 1024 / 1024),
-                });
+#[tauri::command]
+pub async fn get_disk_info() -> Result<Vec<DiskInfo>, String> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+
+    let disk_info = disks
+        .list()
+        .iter()
+        .map(|disk| {
+            let kind = if disk.is_removable() {
+                DiskKind::Removable
+            } else {
+                match disk.kind() {
+                    sysinfo::DiskKind::SSD => DiskKind::Ssd,
+                    sysinfo::DiskKind::HDD => DiskKind::Hdd,
+                    sysinfo::DiskKind::Unknown(_) => DiskKind::Unknown,
+                }
+            };
+            let total = disk.total_space();
+            let free = disk.available_space();
+
+            DiskInfo {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().display().to_string(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                kind,
+                total,
+                used: total.saturating_sub(free),
+                free,
+                is_read_only: disk.is_read_only(),
             }
-        }
+        })
+        .collect();
+
+    Ok(disk_info)
+}
+
+/**
+ * 获取网络信息（`include_public_ip` 默认为 false，为 true 时才会请求外部服务查询公网 IP）
+ */
+#[tauri::command]
+pub async fn get_network_info(include_public_ip: Option<bool>) -> Result<NetworkInfo, String> {
+    let local_ip = local_ip_address::local_ip().ok().map(|ip| ip.to_string());
+    let mac_address = mac_address::get_mac_address()
+        .ok()
+        .flatten()
+        .map(|mac| mac.to_string());
+    let public_ip = if include_public_ip.unwrap_or(false) {
+        fetch_public_ip().await
     } else {
-        // macOS/Linux
-        if lines.len() >= 2 {
-            let parts: Vec<&str> = lines[1].split_whitespace().collect();
-            if parts.len() >= 4 {
-                return Ok(DiskInfo {
-                    total: parts[1].to_string(),
-                    used: parts[2].to_string(),
-                    free: parts[3].to_string(),
-                });
+        None
+    };
+
+    Ok(NetworkInfo {
+        local_ip,
+        mac_address,
+        public_ip,
+    })
+}
+
+/**
+ * 查询公网 IP
+ */
+async fn fetch_public_ip() -> Option<String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .ok()?;
+    let text = client
+        .get("https://api.ipify.org")
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?
+        .text()
+        .await
+        .ok()?;
+    Some(text.trim().to_string())
+}
+
+/**
+ * 按选项构建文件对话框
+ */
+fn file_dialog(
+    app: &AppHandle,
+    window: &WebviewWindow,
+    options: &FileDialogOptions,
+) -> FileDialogBuilder<tauri::Wry> {
+    let mut dialog = app.dialog().file();
+
+    // 以调用方窗口为父窗口，使对话框模态于该窗口
+    #[cfg(desktop)]
+    {
+        dialog = dialog.set_parent(window);
+    }
+    #[cfg(mobile)]
+    let _ = window; // 避免未使用变量警告
+
+    if let Some(title) = &options.title {
+        dialog = dialog.set_title(title);
+    }
+    if let Some(default_path) = options.default_path.as_deref().map(Path::new) {
+        // 默认路径可以是目录，也可以是文件（此时使用其所在目录与文件名）
+        if default_path.is_dir() {
+            dialog = dialog.set_directory(default_path);
+        } else {
+            if let Some(parent) = default_path.parent().filter(|p| p.is_dir()) {
+                dialog = dialog.set_directory(parent);
+            }
+            if let Some(file_name) = default_path.file_name() {
+                dialog = dialog.set_file_name(file_name.to_string_lossy());
             }
         }
     }
+    if let Some(file_name) = &options.file_name {
+        dialog = dialog.set_file_name(file_name);
+    }
+    for filter in options.filters.iter().flatten() {
+        let extensions: Vec<&str> = filter.extensions.iter().map(String::as_str).collect();
+        dialog = dialog.add_filter(&filter.name, &extensions);
+    }
+
+    dialog
+}
+
+/**
+ * 将对话框返回的路径转换为字符串
+ */
+fn file_path_to_string(file_path: FilePath) -> String {
+    match file_path {
+        FilePath::Path(path) => path.display().to_string(),
+        FilePath::Url(url) => url.to_string(),
+    }
+}
+
+/**
+ * 将用户选择的本地路径登记为文件系统授权（打开操作同时记录到最近打开列表），并转换为字符串
+ */
+fn accept_file_path(app: &AppHandle, file_path: FilePath, recent: Option<RecentKind>) -> String {
+    if let FilePath::Path(path) = &file_path {
+        scoped_fs::grant(app, path);
+        if let Some(kind) = recent {
+            recent::record(app, path, kind);
+        }
+    }
+    file_path_to_string(file_path)
+}
 
-    Err("解析硬盘信息失败".to_string())
+/**
+ * 等待对话框回调结果
+ *
+ * 用户取消时返回 None；调用方窗口在对话框关闭前被销毁时返回错误。等待期间不阻塞运行时线程
+ */
+pub(crate) async fn wait_for_dialog<T>(
+    window: &WebviewWindow,
+    rx: oneshot::Receiver<Option<T>>,
+) -> Result<Option<T>, String> {
+    let (closed_tx, closed_rx) = oneshot::channel();
+    let listener = window.once("tauri://destroyed", move |_| {
+        let _ = closed_tx.send(());
+    });
+
+    let result = tokio::select! {
+        result = rx => result.map_err(|e| t!("error-receive-dialog-result", error = e)),
+        _ = closed_rx => Err(t!("error-dialog-window-closed")),
+    };
+
+    window.unlisten(listener);
+    result
 }
 
 /**
  * 获取文件路径（打开文件对话框）
  */
 #[tauri::command]
-pub async fn get_file_path(app: AppHandle) -> Result<Option<String>, String> {
-    use std::sync::mpsc;
-    use std::sync::{Arc, Mutex};
-
-    let (tx, rx) = mpsc::channel();
-    let tx = Arc::new(Mutex::new(Some(tx)));
-
-    app.dialog()
-        .file()
-        .add_filter("所有文件", &["*"])
-        .pick_file(move |file_path| {
-            if let Some(sender) = tx.lock().unwrap().take() {
-                let _ = sender.send(file_path);
-            }
-        });
+pub async fn get_file_path(
+    app: AppHandle,
+    window: WebviewWindow,
+    options: Option<FileDialogOptions>,
+) -> Result<Option<String>, String> {
+    let options = options.unwrap_or_default();
+    let mut dialog = file_dialog(&app, &window, &options);
+    if options.filters.is_none() {
+        dialog = dialog.add_filter(t!("dialog-all-files"), &["*"]);
+    }
+
+    let (tx, rx) = oneshot::channel();
+    dialog.pick_file(move |file_path| {
+        let _ = tx.send(file_path);
+    });
+
+    let file_path = wait_for_dialog(&window, rx).await?;
+    Ok(file_path.map(|file_path| accept_file_path(&app, file_path, Some(RecentKind::File))))
+}
+
+/**
+ * 获取多个文件路径（多选打开文件对话框），用户取消时返回空列表
+ */
+#[tauri::command]
+pub async fn get_file_paths(
+    app: AppHandle,
+    window: WebviewWindow,
+    options: Option<FileDialogOptions>,
+) -> Result<Vec<String>, String> {
+    let options = options.unwrap_or_default();
+    let mut dialog = file_dialog(&app, &window, &options);
+    if options.filters.is_none() {
+        dialog = dialog.add_filter(t!("dialog-all-files"), &["*"]);
+    }
 
-    let file_path = rx.recv().map_err(|e| format!("接收文件路径失败: {e}"))?;
-    Ok(file_path.map(|file_path| match file_path {
-        tauri_plugin_dialog::FilePath::Path(path) => path.display().to_string(),
-        tauri_plugin_dialog::FilePath::Url(url) => url.to_string(),
-    }))
+    let (tx, rx) = oneshot::channel();
+    dialog.pick_files(move |file_paths| {
+        let _ = tx.send(file_paths);
+    });
+
+    let file_paths = wait_for_dialog(&window, rx).await?.unwrap_or_default();
+    // 多选的文件一次性记录到最近打开列表
+    let opened: Vec<_> = file_paths
+        .iter()
+        .filter_map(|file_path| match file_path {
+            FilePath::Path(path) => Some((path.as_path(), RecentKind::File)),
+            FilePath::Url(_) => None,
+        })
+        .collect();
+    recent::record_many(&app, &opened);
+    Ok(file_paths
+        .into_iter()
+        .map(|file_path| accept_file_path(&app, file_path, None))
+        .collect())
+}
+
+/**
+ * 获取保存路径（保存文件对话框）
+ */
+#[tauri::command]
+pub async fn save_file_path(
+    app: AppHandle,
+    window: WebviewWindow,
+    options: Option<FileDialogOptions>,
+) -> Result<Option<String>, String> {
+    let options = options.unwrap_or_default();
+    let (tx, rx) = oneshot::channel();
+    file_dialog(&app, &window, &options).save_file(move |file_path| {
+        let _ = tx.send(file_path);
+    });
+
+    let file_path = wait_for_dialog(&window, rx).await?;
+    Ok(file_path.map(|file_path| accept_file_path(&app, file_path, None)))
 }
 
 /**
  * 获取目录路径（打开目录对话框）
+ *
+ * 移动平台没有目录选择对话框，此时返回 None
  */
 #[tauri::command]
-pub async fn get_directory_path(app: AppHandle) -> Result<Option<String>, String> {
+pub async fn get_directory_path(
+    app: AppHandle,
+    window: WebviewWindow,
+    options: Option<FileDialogOptions>,
+) -> Result<Option<String>, String> {
     #[cfg(desktop)]
     {
-        use std::sync::mpsc;
-        use std::sync::{Arc, Mutex};
-
-        let (tx, rx) = mpsc::channel();
-        let tx = Arc::new(Mutex::new(Some(tx)));
-
-        app.dialog().file().pick_folder(move |dir_path| {
-            if let Some(sender) = tx.lock().unwrap().take() {
-                let _ = sender.send(dir_path);
-            }
+        let options = options.unwrap_or_default();
+        let (tx, rx) = oneshot::channel();
+        file_dialog(&app, &window, &options).pick_folder(move |dir_path| {
+            let _ = tx.send(dir_path);
         });
 
-        let dir_path = rx.recv().map_err(|e| format!("接收目录路径失败: {e}"))?;
-        Ok(dir_path.map(|dir_path| match dir_path {
-            tauri_plugin_dialog::FilePath::Path(path) => path.display().to_string(),
-            tauri_plugin_dialog::FilePath::Url(url) => url.to_string(),
-        }))
+        let dir_path = wait_for_dialog(&window, rx).await?;
+        Ok(dir_path.map(|dir_path| accept_file_path(&app, dir_path, Some(RecentKind::Directory))))
     }
 
     #[cfg(mobile)]
     {
-        let _ = app; // 避免未使用变量警告
-                     // 移动平台不支持目录选择对话框
-        Err("移动平台不支持目录选择功能".to_string())
+        let _ = (app, window, options); // 避免未使用变量警告
+        log::warn!("Directory picker is not supported on mobile platforms");
+        Ok(None)
     }
 }
 
@@ -293,13 +531,13 @@ pub async fn open_window(app: AppHandle, path: String) -> Result<(), String> {
         "child_window",
         tauri::WebviewUrl::App(format!("#{path}").into()),
     )
-    .title("子窗口")
+    .title(t!("window-child-title"))
     .inner_size(900.0, 720.0)
     .center()
     .resizable(false)
     .visible(true)
     .build()
-    .map_err(|e| format!("创建窗口失败: {e}"))?;
+    .map_err(|e| t!("error-create-window", error = e))?;
 
     #[cfg(mobile)]
     let _window = tauri::WebviewWindowBuilder::new(
@@ -308,7 +546,7 @@ pub async fn open_window(app: AppHandle, path: String) -> Result<(), String> {
         tauri::WebviewUrl::App(format!("#{path}").into()),
     )
     .build()
-    .map_err(|e| format!("创建窗口失败: {e}"))?;
+    .map_err(|e| t!("error-create-window", error = e))?;
 
     Ok(())
 }
@@ -326,8 +564,14 @@ pub async fn app_close(app: AppHandle) -> Result<(), String> {
  * HTTP GET 请求
  */
 #[tauri::command]
-pub async fn http_get(url: String, config: Option<HttpConfig>) -> Result<HttpResponse, String> {
-    make_http_request("GET".to_string(), url, None, config).await
+pub async fn http_get(
+    http_errors: State<'_, HttpErrorLog>,
+    url: String,
+    config: Option<HttpConfig>,
+) -> Result<HttpResponse, String> {
+    let result = make_http_request("GET".to_string(), url.clone(), None, config).await;
+    http_errors.record_result("GET", &url, &result);
+    result
 }
 
 /**
@@ -335,18 +579,24 @@ pub async fn http_get(url: String, config: Option<HttpConfig>) -> Result<HttpRes
  */
 #[tauri::command]
 pub async fn http_post(
+    http_errors: State<'_, HttpErrorLog>,
     url: String,
     data: Option<serde_json::Value>,
     config: Option<HttpConfig>,
 ) -> Result<HttpResponse, String> {
-    make_http_request("POST".to_string(), url, data, config).await
+    let result = make_http_request("POST".to_string(), url.clone(), data, config).await;
+    http_errors.record_result("POST", &url, &result);
+    result
 }
 
 /**
  * 通用 HTTP 请求
  */
 #[tauri::command]
-pub async fn http_request(options: HttpOptions) -> Result<HttpResponse, String> {
+pub async fn http_request(
+    http_errors: State<'_, HttpErrorLog>,
+    options: HttpOptions,
+) -> Result<HttpResponse, String> {
     let method = options.method.unwrap_or_else(|| "GET".to_string());
     let config = HttpConfig {
         timeout: options.timeout,
@@ -354,7 +604,15 @@ pub async fn http_request(options: HttpOptions) -> Result<HttpResponse, String>
         response_type: options.response_type,
     };
 
-    make_http_request(method, options.url, options.data, Some(config)).await
+    let result = make_http_request(
+        method.clone(),
+        options.url.clone(),
+        options.data,
+        Some(config),
+    )
+    .await;
+    http_errors.record_result(&method, &options.url, &result);
+    result
 }
 
 /**
@@ -372,7 +630,7 @@ async fn make_http_request(
         .timeout(std::time::Duration::from_millis(timeout))
         .user_agent("Tauri-App/0.0.1")
         .build()
-        .map_err(|e| format!("创建 HTTP 客户端失败: {e}"))?;
+        .map_err(|e| t!("error-create-http-client", error = e))?;
 
     let mut request_builder = match method.to_uppercase().as_str() {
         "GET" => client.get(&url),
@@ -380,7 +638,7 @@ async fn make_http_request(
         "PUT" => client.put(&url),
         "DELETE" => client.delete(&url),
         "PATCH" => client.patch(&url),
-        _ => return Err(format!("不支持的 HTTP 方法: {method}")),
+        _ => return Err(t!("error-unsupported-http-method", method = method)),
     };
 
     // 添加自定义头部
@@ -397,11 +655,11 @@ async fn make_http_request(
         request_builder = request_builder.json(&data);
     }
 
-    // 发送请求
+    // 发送请求（错误信息去掉 URL，避免查询参数中的令牌进入日志与诊断包）
     let response = request_builder
         .send()
         .await
-        .map_err(|e| format!("HTTP 请求失败: {e}"))?;
+        .map_err(|e| t!("error-http-request", error = e.without_url()))?;
 
     let status = response.status().as_u16();
     let status_text = response
@@ -426,14 +684,14 @@ async fn make_http_request(
                 let bytes = response
                     .bytes()
                     .await
-                    .map_err(|e| format!("读取响应数据失败: {e}"))?;
+                    .map_err(|e| t!("error-read-response-data", error = e.without_url()))?;
                 serde_json::Value::String(general_purpose::STANDARD.encode(bytes))
             }
             Some("base64") => {
                 let bytes = response
                     .bytes()
                     .await
-                    .map_err(|e| format!("读取响应数据失败: {e}"))?;
+                    .map_err(|e| t!("error-read-response-data", error = e.without_url()))?;
                 serde_json::Value::String(general_purpose::STANDARD.encode(bytes))
             }
             _ => {
@@ -441,7 +699,7 @@ async fn make_http_request(
                 let text = response
                     .text()
                     .await
-                    .map_err(|e| format!("读取响应文本失败: {e}"))?;
+                    .map_err(|e| t!("error-read-response-text", error = e.without_url()))?;
 
                 match serde_json::from_str::<serde_json::Value>(&text) {
                     Ok(json) => json,
@@ -454,7 +712,7 @@ async fn make_http_request(
         let text = response
             .text()
             .await
-            .map_err(|e| format!("读取响应文本失败: {e}"))?;
+            .map_err(|e| t!("error-read-response-text", error = e.without_url()))?;
 
         match serde_json::from_str::<serde_json::Value>(&text) {
             Ok(json) => json,
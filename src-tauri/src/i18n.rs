@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::settings::SettingsState;
+
+/// 回退语言：当前语言缺少某条消息时使用
+pub const FALLBACK_LOCALE: &str = "zh-CN";
+/// 系统语言不受支持时使用的语言
+const DEFAULT_SYSTEM_LOCALE: &str = "en-US";
+/// 语言切换后发送的事件名
+pub const LOCALE_CHANGED_EVENT: &str = "locale-changed";
+
+/// 支持的语言及其消息目录
+const CATALOGS: &[(&str, &str)] = &[
+    ("zh-CN", include_str!("../locales/zh-CN.ftl")),
+    ("en-US", include_str!("../locales/en-US.ftl")),
+];
+
+static BUNDLES: OnceLock<HashMap<&'static str, FluentBundle<FluentResource>>> = OnceLock::new();
+static CURRENT_LOCALE: RwLock<&str> = RwLock::new(FALLBACK_LOCALE);
+
+/// 翻译消息，可带命名参数：`t!("error-create-window", error = e)`
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = ::fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value.to_string());)+
+        $crate::i18n::translate($key, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+fn bundles() -> &'static HashMap<&'static str, FluentBundle<FluentResource>> {
+    BUNDLES.get_or_init(|| {
+        CATALOGS
+            .iter()
+            .map(|(locale, source)| {
+                let resource = FluentResource::try_new(source.to_string())
+                    .unwrap_or_else(|_| panic!("invalid message catalog: {locale}"));
+                let mut bundle = FluentBundle::new_concurrent(vec![locale
+                    .parse()
+                    .expect("invalid locale identifier")]);
+                // 不插入 Unicode 方向隔离符，避免出现在菜单与对话框文本中
+                bundle.set_use_isolating(false);
+                bundle
+                    .add_resource(resource)
+                    .unwrap_or_else(|_| panic!("duplicate message in catalog: {locale}"));
+                (*locale, bundle)
+            })
+            .collect()
+    })
+}
+
+/**
+ * 按当前语言翻译消息，缺失时依次回退到回退语言与消息 ID 本身
+ */
+pub fn translate(key: &str, args: Option<&FluentArgs>) -> String {
+    let bundles = bundles();
+
+    for locale in [current_locale(), FALLBACK_LOCALE] {
+        let Some(bundle) = bundles.get(locale) else {
+            continue;
+        };
+        if let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) {
+            let mut errors = Vec::new();
+            return bundle
+                .format_pattern(pattern, args, &mut errors)
+                .into_owned();
+        }
+    }
+
+    key.to_string()
+}
+
+/**
+ * 当前语言
+ */
+pub fn current_locale() -> &'static str {
+    *CURRENT_LOCALE.read().unwrap()
+}
+
+/**
+ * 将任意语言标签（如 `en_GB`、`zh-Hans-CN`）匹配到支持的语言
+ */
+pub fn negotiate(requested: &str) -> Option<&'static str> {
+    let requested = requested.replace('_', "-");
+
+    if let Some((locale, _)) = CATALOGS
+        .iter()
+        .find(|(locale, _)| locale.eq_ignore_ascii_case(&requested))
+    {
+        return Some(locale);
+    }
+
+    let language = requested.split('-').next()?.to_lowercase();
+    CATALOGS
+        .iter()
+        .find(|(locale, _)| locale.split('-').next() == Some(language.as_str()))
+        .map(|(locale, _)| *locale)
+}
+
+/**
+ * 检测系统语言
+ */
+pub fn detect_system_locale() -> &'static str {
+    sys_locale::get_locale()
+        .and_then(|locale| negotiate(&locale))
+        .unwrap_or(DEFAULT_SYSTEM_LOCALE)
+}
+
+/**
+ * 根据设置（为空时跟随系统）初始化当前语言
+ */
+pub fn init(settings: &SettingsState) {
+    let locale = settings
+        .get()
+        .locale
+        .and_then(|locale| negotiate(&locale))
+        .unwrap_or_else(detect_system_locale);
+    *CURRENT_LOCALE.write().unwrap() = locale;
+}
+
+/**
+ * 获取当前语言
+ */
+#[tauri::command]
+pub async fn get_locale() -> Result<String, String> {
+    Ok(current_locale().to_string())
+}
+
+/**
+ * 获取支持的语言列表
+ */
+#[tauri::command]
+pub async fn get_available_locales() -> Result<Vec<String>, String> {
+    Ok(CATALOGS
+        .iter()
+        .map(|(locale, _)| locale.to_string())
+        .collect())
+}
+
+/**
+ * 切换语言（为空时跟随系统），保存到设置并重建原生菜单
+ */
+#[tauri::command]
+pub async fn set_locale(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    locale: Option<String>,
+) -> Result<String, String> {
+    let resolved = match &locale {
+        Some(requested) => negotiate(requested)
+            .ok_or_else(|| t!("error-unsupported-locale", locale = requested))?,
+        None => detect_system_locale(),
+    };
+
+    *CURRENT_LOCALE.write().unwrap() = resolved;
+    settings.update(&app, |settings| {
+        settings.locale = locale.map(|_| resolved.to_string());
+    })?;
+
+    #[cfg(desktop)]
+    crate::menu::refresh(&app).map_err(|e| t!("error-update-menu", error = e))?;
+
+    let _ = app.emit(LOCALE_CHANGED_EVENT, resolved);
+    Ok(resolved.to_string())
+}
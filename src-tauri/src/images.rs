@@ -0,0 +1,408 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Cursor, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+use serde::{Deserialize, Serialize};
+use tauri::ipc::Response;
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+
+/// 缩略图缓存目录名（位于应用缓存目录）
+const THUMBNAIL_DIR: &str = "thumbnails";
+/// 缩略图最大边长
+const MAX_THUMBNAIL_SIZE: u32 = 2048;
+/// 转换时目标宽高的上限
+const MAX_CONVERT_SIZE: u32 = 16384;
+/// 临时文件的序号，避免并发写入同一目标路径时互相覆盖
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+/// 默认 JPEG 质量
+const DEFAULT_JPEG_QUALITY: u8 = 85;
+/// 支持读取的图片格式
+const INPUT_FORMATS: &[ImageFormat] = &[
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+    ImageFormat::Gif,
+];
+
+// 输出图片格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Png,
+    Jpeg,
+    /// 无损 WebP
+    Webp,
+    Gif,
+}
+
+impl OutputFormat {
+    fn image_format(self) -> ImageFormat {
+        match self {
+            Self::Png => ImageFormat::Png,
+            Self::Jpeg => ImageFormat::Jpeg,
+            Self::Webp => ImageFormat::WebP,
+            Self::Gif => ImageFormat::Gif,
+        }
+    }
+
+    fn from_path(path: &Path) -> Option<Self> {
+        match ImageFormat::from_path(path).ok()? {
+            ImageFormat::Png => Some(Self::Png),
+            ImageFormat::Jpeg => Some(Self::Jpeg),
+            ImageFormat::WebP => Some(Self::Webp),
+            ImageFormat::Gif => Some(Self::Gif),
+            _ => None,
+        }
+    }
+}
+
+// 图片元数据
+#[derive(Debug, Serialize)]
+pub struct ImageMetadata {
+    format: String,
+    /// 文件中存储的像素尺寸（未应用方向）
+    width: u32,
+    height: u32,
+    /// EXIF 方向（1–8），没有时为空
+    orientation: Option<u32>,
+    /// 主图像的 EXIF 字段（标签名 → 显示值）
+    exif: BTreeMap<String, String>,
+}
+
+// 图片转换选项
+#[derive(Debug, Default, Deserialize)]
+pub struct ConvertOptions {
+    /// 输出格式，未指定时按输出文件扩展名判断
+    pub format: Option<OutputFormat>,
+    /// 目标宽高，只指定一个时按比例缩放
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// 同时指定宽高时是否保持比例（缩放到不超过目标尺寸，默认 true）
+    pub keep_aspect_ratio: Option<bool>,
+    /// JPEG 质量（1–100，默认 85）
+    pub quality: Option<u8>,
+}
+
+// 图片转换结果
+#[derive(Debug, Serialize)]
+pub struct ConvertResult {
+    path: String,
+    width: u32,
+    height: u32,
+}
+
+/**
+ * 解码图片并按 EXIF 方向旋转，只接受 PNG、JPEG、WebP 与 GIF
+ */
+fn decode(path: &Path) -> Result<DynamicImage, String> {
+    let error = |e: &dyn std::fmt::Display| t!("error-decode-image", error = e);
+    let reader = ImageReader::open(path)
+        .and_then(|reader| reader.with_guessed_format())
+        .map_err(|e| error(&e))?;
+    match reader.format() {
+        Some(format) if INPUT_FORMATS.contains(&format) => {}
+        format => {
+            let format = format.map(|f| format!("{f:?}")).unwrap_or_default();
+            return Err(t!("error-unsupported-image-format", format = format));
+        }
+    }
+
+    let mut decoder = reader.into_decoder().map_err(|e| error(&e))?;
+    let orientation = decoder.orientation().map_err(|e| error(&e))?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|e| error(&e))?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/**
+ * 编码图片（JPEG 不支持透明通道，WebP 与 GIF 编码器需要 RGBA）
+ */
+fn encode(
+    image: &DynamicImage,
+    format: OutputFormat,
+    quality: u8,
+    writer: &mut (impl std::io::Write + std::io::Seek),
+) -> Result<(), String> {
+    let result = match format {
+        OutputFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(writer, quality)),
+        OutputFormat::Webp | OutputFormat::Gif => {
+            DynamicImage::ImageRgba8(image.to_rgba8()).write_to(writer, format.image_format())
+        }
+        OutputFormat::Png => image.write_to(writer, format.image_format()),
+    };
+    result.map_err(|e| t!("error-encode-image", error = e))
+}
+
+/**
+ * 缩略图缓存路径：按源路径、尺寸与格式生成文件名
+ */
+fn thumbnail_path(
+    app: &AppHandle,
+    source: &Path,
+    size: u32,
+    format: OutputFormat,
+) -> Result<PathBuf, String> {
+    let key = format!("{}\n{size}\n{format:?}", source.display());
+    let extension = format.image_format().extensions_str()[0];
+    app.path()
+        .app_cache_dir()
+        .map(|dir| {
+            dir.join(THUMBNAIL_DIR).join(format!(
+                "{}.{extension}",
+                blake3::hash(key.as_bytes()).to_hex()
+            ))
+        })
+        .map_err(|e| t!("error-app-cache-dir", error = e))
+}
+
+/**
+ * 获取缩略图（路径需位于已授权范围内），以二进制返回图片数据
+ *
+ * size 为最长边（默认 256），format 默认 jpeg；缩略图缓存在应用缓存目录，
+ * 缓存文件的修改时间与源文件一致时直接返回，源文件修改后重新生成
+ */
+#[tauri::command]
+pub async fn get_image_thumbnail(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    path: String,
+    size: Option<u32>,
+    format: Option<OutputFormat>,
+) -> Result<Response, String> {
+    let source = scoped_fs::resolve(&settings, &path)?;
+    let size = size.unwrap_or(256);
+    if size == 0 || size > MAX_THUMBNAIL_SIZE {
+        return Err(t!("error-invalid-thumbnail-size", max = MAX_THUMBNAIL_SIZE));
+    }
+    let format = format.unwrap_or(OutputFormat::Jpeg);
+    let cache_path = thumbnail_path(&app, &source, size, format)?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let modified = fs::metadata(&source)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| t!("error-fs-io", error = e))?;
+        let cached = fs::metadata(&cache_path)
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|cached| cached == modified);
+        if cached {
+            if let Ok(bytes) = fs::read(&cache_path) {
+                return Ok(Response::new(bytes));
+            }
+        }
+
+        let thumbnail = decode(&source)?.thumbnail(size, size);
+        let mut bytes = Cursor::new(Vec::new());
+        encode(&thumbnail, format, DEFAULT_JPEG_QUALITY, &mut bytes)?;
+        let bytes = bytes.into_inner();
+
+        // 先写入临时文件并设置修改时间，再重命名，避免并发读取到不完整的缓存；
+        // 写入缓存失败不影响返回结果
+        let temp_path = cache_path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let write_cache = || -> std::io::Result<()> {
+            if let Some(dir) = cache_path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut file = File::create(&temp_path)?;
+            file.write_all(&bytes)?;
+            file.set_modified(modified)?;
+            drop(file);
+            fs::rename(&temp_path, &cache_path)
+        };
+        if let Err(e) = write_cache() {
+            let _ = fs::remove_file(&temp_path);
+            log::warn!("Failed to cache thumbnail: {e}");
+        }
+        Ok(Response::new(bytes))
+    })
+    .await
+    .map_err(|e| t!("error-decode-image", error = e))?
+}
+
+/**
+ * 清空缩略图缓存
+ */
+#[tauri::command]
+pub async fn clear_thumbnail_cache(app: AppHandle) -> Result<(), String> {
+    let dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| t!("error-app-cache-dir", error = e))?
+        .join(THUMBNAIL_DIR);
+    if !dir.exists() {
+        return Ok(());
+    }
+    fs::remove_dir_all(dir).map_err(|e| t!("error-fs-io", error = e))
+}
+
+/**
+ * 读取图片尺寸、格式与 EXIF 元数据（路径需位于已授权范围内）
+ */
+#[tauri::command]
+pub async fn get_image_metadata(
+    settings: State<'_, SettingsState>,
+    path: String,
+) -> Result<ImageMetadata, String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let error = |e: &dyn std::fmt::Display| t!("error-decode-image", error = e);
+        let reader = ImageReader::open(&path)
+            .and_then(|reader| reader.with_guessed_format())
+            .map_err(|e| error(&e))?;
+        let format = reader
+            .format()
+            .map(|format| format.to_mime_type().to_string())
+            .unwrap_or_default();
+        let (width, height) = reader.into_dimensions().map_err(|e| error(&e))?;
+
+        // 没有 EXIF 数据的图片返回空字段
+        let exif = File::open(&path).ok().and_then(|file| {
+            exif::Reader::new()
+                .read_from_container(&mut BufReader::new(file))
+                .ok()
+        });
+        let orientation = exif.as_ref().and_then(|exif| {
+            exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+                .and_then(|field| field.value.get_uint(0))
+        });
+        let fields = exif
+            .as_ref()
+            .map(|exif| {
+                exif.fields()
+                    .filter(|field| field.ifd_num == exif::In::PRIMARY)
+                    .map(|field| {
+                        (
+                            field.tag.to_string(),
+                            field.display_value().with_unit(exif).to_string(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(ImageMetadata {
+            format,
+            width,
+            height,
+            orientation,
+            exif: fields,
+        })
+    })
+    .await
+    .map_err(|e| t!("error-decode-image", error = e))?
+}
+
+/**
+ * 按比例计算另一边的长度：`side` 缩放为 `target / reference` 倍，结果超过上限时返回错误
+ */
+fn scale_side(side: u32, target: u32, reference: u32) -> Result<u32, String> {
+    let reference = u64::from(reference.max(1));
+    let scaled = (u64::from(side) * u64::from(target) + reference / 2) / reference;
+    u32::try_from(scaled.max(1))
+        .ok()
+        .filter(|scaled| *scaled <= MAX_CONVERT_SIZE)
+        .ok_or_else(|| t!("error-invalid-convert-size", max = MAX_CONVERT_SIZE))
+}
+
+/**
+ * 转换图片格式或缩放尺寸并保存到目标路径（源与目标都必须位于授权范围内）
+ */
+#[tauri::command]
+pub async fn convert_image(
+    settings: State<'_, SettingsState>,
+    source: String,
+    output: String,
+    options: Option<ConvertOptions>,
+) -> Result<ConvertResult, String> {
+    let source = scoped_fs::resolve(&settings, &source)?;
+    let output = scoped_fs::resolve(&settings, &output)?;
+    let options = options.unwrap_or_default();
+    let invalid_size = |size: &u32| *size == 0 || *size > MAX_CONVERT_SIZE;
+    if options
+        .width
+        .iter()
+        .chain(&options.height)
+        .any(invalid_size)
+    {
+        return Err(t!("error-invalid-convert-size", max = MAX_CONVERT_SIZE));
+    }
+    let format = options
+        .format
+        .or_else(|| OutputFormat::from_path(&output))
+        .ok_or_else(|| {
+            t!(
+                "error-unsupported-image-format",
+                format = output.display().to_string()
+            )
+        })?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut image = decode(&source)?;
+        image = match (options.width, options.height) {
+            (Some(width), Some(height)) if options.keep_aspect_ratio.unwrap_or(true) => {
+                image.resize(width, height, image::imageops::FilterType::Lanczos3)
+            }
+            (Some(width), Some(height)) => {
+                image.resize_exact(width, height, image::imageops::FilterType::Lanczos3)
+            }
+            (Some(width), None) => {
+                let height = scale_side(image.height(), width, image.width())?;
+                image.resize_exact(width, height, image::imageops::FilterType::Lanczos3)
+            }
+            (None, Some(height)) => {
+                let width = scale_side(image.width(), height, image.height())?;
+                image.resize_exact(width, height, image::imageops::FilterType::Lanczos3)
+            }
+            (None, None) => image,
+        };
+
+        let quality = options
+            .quality
+            .unwrap_or(DEFAULT_JPEG_QUALITY)
+            .clamp(1, 100);
+        // 先写入同目录下的临时文件再重命名，失败时不会破坏已有文件（包括输出路径就是源文件的情况）
+        let temp_path = output.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = File::create(&temp_path).map_err(|e| t!("error-encode-image", error = e))?;
+        let mut writer = BufWriter::new(file);
+        let result = encode(&image, format, quality, &mut writer)
+            .and_then(|()| {
+                // 显式刷新缓冲区，写入错误不能在 drop 时被忽略
+                writer
+                    .into_inner()
+                    .map(drop)
+                    .map_err(|e| t!("error-encode-image", error = e.error()))
+            })
+            .and_then(|()| {
+                fs::rename(&temp_path, &output).map_err(|e| t!("error-encode-image", error = e))
+            });
+        if let Err(e) = result {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+
+        Ok(ConvertResult {
+            path: output.display().to_string(),
+            width: image.width(),
+            height: image.height(),
+        })
+    })
+    .await
+    .map_err(|e| t!("error-encode-image", error = e))?
+}
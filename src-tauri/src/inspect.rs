@@ -0,0 +1,298 @@
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+use crate::tasks::{CancelToken, TaskRegistry};
+
+/// MIME 嗅探读取的字节数
+const SNIFF_BYTES: usize = 8 * 1024;
+/// 哈希计算的读取块大小
+const HASH_CHUNK_BYTES: usize = 1024 * 1024;
+/// 进度事件的最小发送间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+// 哈希算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+    Md5,
+}
+
+// 文件摘要（十六进制），未请求的算法为空
+#[derive(Debug, Default, Serialize)]
+pub struct FileDigests {
+    sha256: Option<String>,
+    blake3: Option<String>,
+    md5: Option<String>,
+}
+
+// 文件检查任务事件
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum InspectEvent {
+    /// 摘要计算进度（字节）
+    Progress {
+        processed: u64,
+        total: u64,
+    },
+    Finished(Box<FileInspection>),
+    Cancelled,
+    Failed {
+        message: String,
+    },
+}
+
+// 文件检查结果
+#[derive(Debug, Serialize)]
+pub struct FileInspection {
+    path: String,
+    name: String,
+    size: u64,
+    is_dir: bool,
+    is_file: bool,
+    is_symlink: bool,
+    /// 符号链接指向的路径
+    symlink_target: Option<String>,
+    /// 时间戳（Unix 毫秒），平台不支持时为空
+    created: Option<u64>,
+    modified: Option<u64>,
+    accessed: Option<u64>,
+    readonly: bool,
+    /// Unix 权限位（如 `0o644`），非 Unix 平台为空
+    mode: Option<u32>,
+    /// Unix 权限字符串（如 `rw-r--r--`）
+    permissions: Option<String>,
+    /// 根据文件头识别的 MIME 类型，无法识别时按文本或二进制处理
+    mime_type: String,
+    /// 与识别出的类型对应的扩展名
+    detected_extension: Option<String>,
+    digests: Option<FileDigests>,
+}
+
+fn to_millis(time: std::io::Result<SystemTime>) -> Option<u64> {
+    time.ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+}
+
+/**
+ * 根据文件头嗅探 MIME 类型
+ */
+fn sniff_mime(path: &Path) -> (String, Option<String>) {
+    let mut buffer = Vec::with_capacity(SNIFF_BYTES);
+    let read =
+        File::open(path).and_then(|file| file.take(SNIFF_BYTES as u64).read_to_end(&mut buffer));
+    if read.is_err() || buffer.is_empty() {
+        return ("application/octet-stream".to_string(), None);
+    }
+
+    if let Some(kind) = infer::get(&buffer) {
+        return (
+            kind.mime_type().to_string(),
+            Some(kind.extension().to_string()),
+        );
+    }
+
+    // 无法识别的文件：不含 NUL 且为合法 UTF-8（允许末尾被截断的字符）时视为文本
+    let is_text = !buffer.contains(&0)
+        && match std::str::from_utf8(&buffer) {
+            Ok(_) => true,
+            Err(e) => e.error_len().is_none(),
+        };
+    if is_text {
+        ("text/plain".to_string(), Some("txt".to_string()))
+    } else {
+        ("application/octet-stream".to_string(), None)
+    }
+}
+
+/**
+ * Unix 权限位转换为 `rwxr-xr-x` 形式
+ */
+#[cfg(unix)]
+fn format_mode(mode: u32) -> String {
+    let flags = ['r', 'w', 'x'];
+    (0..9)
+        .map(|bit| {
+            if mode & (1 << (8 - bit)) != 0 {
+                flags[bit % 3]
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/**
+ * 流式计算所选算法的摘要，定期通过 Channel 报告进度
+ */
+fn compute_digests(
+    path: &Path,
+    algorithms: &[HashAlgorithm],
+    token: &CancelToken,
+    on_event: &Channel<InspectEvent>,
+) -> Result<FileDigests, String> {
+    let mut file = File::open(path).map_err(|e| t!("error-hash-file", error = e))?;
+    let total = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+    let mut sha256 = algorithms
+        .contains(&HashAlgorithm::Sha256)
+        .then(Sha256::new);
+    let mut blake3 = algorithms
+        .contains(&HashAlgorithm::Blake3)
+        .then(blake3::Hasher::new);
+    let mut md5 = algorithms.contains(&HashAlgorithm::Md5).then(Md5::new);
+
+    let mut buffer = vec![0; HASH_CHUNK_BYTES];
+    let mut processed = 0;
+    let mut last_progress = Instant::now();
+    loop {
+        if token.is_cancelled() {
+            return Err(t!("error-hash-cancelled"));
+        }
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| t!("error-hash-file", error = e))?;
+        if read == 0 {
+            break;
+        }
+
+        let chunk = &buffer[..read];
+        if let Some(hasher) = &mut sha256 {
+            hasher.update(chunk);
+        }
+        if let Some(hasher) = &mut blake3 {
+            hasher.update(chunk);
+        }
+        if let Some(hasher) = &mut md5 {
+            hasher.update(chunk);
+        }
+
+        processed += read as u64;
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            last_progress = Instant::now();
+            let _ = on_event.send(InspectEvent::Progress { processed, total });
+        }
+    }
+
+    let _ = on_event.send(InspectEvent::Progress { processed, total });
+
+    Ok(FileDigests {
+        sha256: sha256.map(|hasher| format!("{:x}", hasher.finalize())),
+        blake3: blake3.map(|hasher| hasher.finalize().to_hex().to_string()),
+        md5: md5.map(|hasher| format!("{:x}", hasher.finalize())),
+    })
+}
+
+/**
+ * 读取文件信息；`entry` 为条目本身的路径（用于符号链接信息），`target` 为解析后的目标路径
+ */
+fn inspect(
+    entry: &Path,
+    target: &Path,
+    algorithms: &[HashAlgorithm],
+    token: &CancelToken,
+    on_event: &Channel<InspectEvent>,
+) -> Result<FileInspection, String> {
+    let link_metadata = fs::symlink_metadata(entry).map_err(|e| t!("error-fs-io", error = e))?;
+    let is_symlink = link_metadata.file_type().is_symlink();
+    let symlink_target = is_symlink
+        .then(|| fs::read_link(entry).ok())
+        .flatten()
+        .map(|link| link.display().to_string());
+    // 其余信息按符号链接指向的目标返回
+    let metadata = fs::metadata(target).unwrap_or(link_metadata);
+
+    #[cfg(unix)]
+    let mode = {
+        use std::os::unix::fs::PermissionsExt;
+        Some(metadata.permissions().mode() & 0o7777)
+    };
+    #[cfg(not(unix))]
+    let mode: Option<u32> = None;
+
+    let (mime_type, detected_extension) = if metadata.is_file() {
+        sniff_mime(target)
+    } else if metadata.is_dir() {
+        ("inode/directory".to_string(), None)
+    } else {
+        ("application/octet-stream".to_string(), None)
+    };
+
+    let digests = if algorithms.is_empty() || !metadata.is_file() {
+        None
+    } else {
+        Some(compute_digests(target, algorithms, token, on_event)?)
+    };
+
+    Ok(FileInspection {
+        path: entry.display().to_string(),
+        name: entry
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        size: metadata.len(),
+        is_dir: metadata.is_dir(),
+        is_file: metadata.is_file(),
+        is_symlink,
+        symlink_target,
+        created: to_millis(metadata.created()),
+        modified: to_millis(metadata.modified()),
+        accessed: to_millis(metadata.accessed()),
+        readonly: metadata.permissions().readonly(),
+        mode,
+        #[cfg(unix)]
+        permissions: mode.map(format_mode),
+        #[cfg(not(unix))]
+        permissions: None,
+        mime_type,
+        detected_extension,
+        digests,
+    })
+}
+
+/**
+ * 在后台检查文件：元数据、权限、符号链接目标、嗅探的 MIME 类型，以及可选的文件摘要
+ *
+ * 立即返回任务 ID（可用 `cancel_task` 取消），摘要进度与检查结果通过 Channel 推送。
+ * 条目本身与符号链接指向的目标都必须位于授权范围内
+ */
+#[tauri::command]
+pub async fn inspect_file(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    tasks: State<'_, TaskRegistry>,
+    path: String,
+    algorithms: Option<Vec<HashAlgorithm>>,
+    on_event: Channel<InspectEvent>,
+) -> Result<u32, String> {
+    let entry = scoped_fs::resolve_link(&settings, &path)?;
+    let target = scoped_fs::resolve(&settings, &path)?;
+    let algorithms = algorithms.unwrap_or_default();
+    let (task_id, token) = tasks.register();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = inspect(&entry, &target, &algorithms, &token, &on_event);
+        let event = match result {
+            _ if token.is_cancelled() => InspectEvent::Cancelled,
+            Ok(inspection) => InspectEvent::Finished(Box::new(inspection)),
+            Err(message) => InspectEvent::Failed { message },
+        };
+        let _ = on_event.send(event);
+        app.state::<TaskRegistry>().finish(task_id);
+    });
+
+    Ok(task_id)
+}
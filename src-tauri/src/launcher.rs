@@ -0,0 +1,177 @@
+use tauri::{AppHandle, State, Url, WebviewWindow};
+
+use crate::i18n::t;
+use crate::scoped_fs;
+use crate::settings::SettingsState;
+
+/// 允许通过 `open_url` 打开的 URL 协议，其余协议（如 `file:`、自定义协议）一律拒绝
+const ALLOWED_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/**
+ * 解析 URL 并检查协议是否在允许列表中
+ */
+fn check_url(url: &str) -> Result<Url, String> {
+    let parsed = Url::parse(url).map_err(|_| t!("error-invalid-url", url = url))?;
+    if !ALLOWED_URL_SCHEMES.contains(&parsed.scheme()) {
+        return Err(t!("error-url-scheme-not-allowed", scheme = parsed.scheme()));
+    }
+    Ok(parsed)
+}
+
+/**
+ * 检查应用是否已由用户通过 `pick_application` 选择
+ */
+fn check_app(settings: &SettingsState, app: Option<String>) -> Result<Option<String>, String> {
+    match app {
+        Some(app) if !settings.get().launcher_apps.contains(&app) => {
+            Err(t!("error-application-not-allowed", app = app))
+        }
+        app => Ok(app),
+    }
+}
+
+/**
+ * 用系统默认程序打开文件或目录（路径需位于已授权范围内），
+ * 指定 app 时使用该应用打开（应用需先通过 `pick_application` 选择）
+ */
+#[tauri::command]
+pub async fn open_path(
+    settings: State<'_, SettingsState>,
+    path: String,
+    app: Option<String>,
+) -> Result<(), String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+    let app = check_app(&settings, app)?;
+    if !path.exists() {
+        return Err(t!(
+            "error-fs-invalid-path",
+            path = path.display().to_string()
+        ));
+    }
+
+    match app {
+        Some(app) => open::with_detached(&path, app),
+        None => open::that_detached(&path),
+    }
+    .map_err(|e| t!("error-open-item", error = e))
+}
+
+/**
+ * 用系统默认程序打开 URL（仅允许 http、https 与 mailto），
+ * 指定 app 时使用该应用打开（应用需先通过 `pick_application` 选择）
+ */
+#[tauri::command]
+pub async fn open_url(
+    settings: State<'_, SettingsState>,
+    url: String,
+    app: Option<String>,
+) -> Result<(), String> {
+    let url = check_url(&url)?;
+    let app = check_app(&settings, app)?;
+
+    match app {
+        Some(app) => open::with_detached(url.as_str(), app),
+        None => open::that_detached(url.as_str()),
+    }
+    .map_err(|e| t!("error-open-item", error = e))
+}
+
+/**
+ * 在系统文件管理器中显示并选中文件或目录（路径需位于已授权范围内）
+ */
+#[tauri::command]
+pub async fn reveal_path(settings: State<'_, SettingsState>, path: String) -> Result<(), String> {
+    let path = scoped_fs::resolve(&settings, &path)?;
+
+    #[cfg(desktop)]
+    {
+        tauri_plugin_opener::reveal_item_in_dir(&path).map_err(|e| t!("error-open-item", error = e))
+    }
+
+    #[cfg(mobile)]
+    {
+        log::warn!("Revealing {} is not supported on mobile", path.display());
+        Err(t!("error-reveal-unsupported"))
+    }
+}
+
+/**
+ * 弹出对话框让用户选择应用，选择的应用被记录到设置中，之后可用于 `open_path` 与 `open_url`；
+ * 返回应用路径，用户取消时返回 None
+ */
+#[tauri::command]
+pub async fn pick_application(
+    app: AppHandle,
+    window: WebviewWindow,
+    settings: State<'_, SettingsState>,
+) -> Result<Option<String>, String> {
+    #[cfg(desktop)]
+    {
+        use tauri_plugin_dialog::{DialogExt, FilePath};
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        // macOS 的 .app 应用包在对话框中作为文件选择
+        app.dialog()
+            .file()
+            .set_parent(&window)
+            .pick_file(move |file_path| {
+                let _ = tx.send(file_path);
+            });
+
+        let path = match crate::handlers::wait_for_dialog(&window, rx).await? {
+            Some(FilePath::Path(path)) => path,
+            Some(FilePath::Url(url)) => url
+                .to_file_path()
+                .map_err(|_| t!("error-application-not-found", app = url.as_str()))?,
+            None => return Ok(None),
+        };
+        let path = path
+            .canonicalize()
+            .map_err(|_| {
+                t!(
+                    "error-application-not-found",
+                    app = path.display().to_string()
+                )
+            })?
+            .display()
+            .to_string();
+
+        settings.update(&app, |settings| {
+            if !settings.launcher_apps.contains(&path) {
+                settings.launcher_apps.push(path.clone());
+            }
+        })?;
+        Ok(Some(path))
+    }
+
+    #[cfg(mobile)]
+    {
+        let _ = (app, window, settings); // 避免未使用变量警告
+        Err(t!("error-pick-application-unsupported"))
+    }
+}
+
+/**
+ * 获取用户已选择的应用
+ */
+#[tauri::command]
+pub async fn get_launcher_apps(settings: State<'_, SettingsState>) -> Result<Vec<String>, String> {
+    Ok(settings.get().launcher_apps)
+}
+
+/**
+ * 移除已选择的应用
+ */
+#[tauri::command]
+pub async fn remove_launcher_app(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    path: String,
+) -> Result<(), String> {
+    if !settings.get().launcher_apps.contains(&path) {
+        return Err(t!("error-application-not-found", app = path));
+    }
+    settings.update(&app, |settings| {
+        settings.launcher_apps.retain(|existing| *existing != path);
+    })
+}
@@ -1,11 +1,36 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
+mod alerts;
+mod archive;
+mod build_info;
+mod diagnostics;
+mod dir_size;
+mod duplicates;
+mod file_drop;
+mod file_handle;
+mod file_watch;
 mod handlers;
+mod i18n;
+mod images;
+mod inspect;
+mod launcher;
 #[cfg(desktop)]
 mod menu;
+mod metrics;
+mod monitor;
+mod open_files;
+mod process;
+mod recent;
+mod scoped_fs;
+mod settings;
+mod system_info;
+mod tasks;
+mod watchdog;
 
 use std::time::Duration;
 use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
 
+use crate::i18n::t;
+
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {name}! You've been greeted from Rust!")
@@ -25,13 +50,13 @@ async fn create_loading_window(app: AppHandle) -> Result<(), String> {
             .always_on_top(true)
             .visible(false) // 创建时不显示
             .build()
-            .map_err(|e| format!("Failed to create loading window: {e}"))?;
+            .map_err(|e| t!("error-create-loading-window", error = e))?;
 
     #[cfg(mobile)]
     let _loading_window =
         WebviewWindowBuilder::new(&app, "loading", WebviewUrl::App("#/start-loading".into()))
             .build()
-            .map_err(|e| format!("Failed to create loading window: {e}"))?;
+            .map_err(|e| t!("error-create-loading-window", error = e))?;
 
     Ok(())
 }
@@ -44,7 +69,7 @@ async fn notify_loading_ready(app: AppHandle) -> Result<(), String> {
         #[cfg(desktop)]
         _loading_window
             .show()
-            .map_err(|e| format!("Failed to show loading window: {e}"))?;
+            .map_err(|e| t!("error-show-loading-window", error = e))?;
 
         #[cfg(mobile)]
         {
@@ -56,16 +81,17 @@ async fn notify_loading_ready(app: AppHandle) -> Result<(), String> {
         tauri::async_runtime::spawn(async move {
             tokio::time::sleep(Duration::from_millis(1800)).await;
 
-            // 创建主窗口
-            if let Err(e) = create_main_window(app_handle.clone()).await {
-                eprintln!("Failed to create main window: {e}");
+            // 创建主窗口，随后发送排队的启动文件
+            match create_main_window(app_handle.clone()).await {
+                Ok(()) => open_files::main_window_ready(&app_handle),
+                Err(e) => log::error!("Failed to create main window: {e}"),
             }
 
             // 关闭 loading 窗口
             if let Some(loading_win) = app_handle.get_webview_window("loading") {
                 #[cfg(desktop)]
                 if let Err(e) = loading_win.close() {
-                    eprintln!("Failed to close loading window: {e}");
+                    log::error!("Failed to close loading window: {e}");
                 }
 
                 #[cfg(mobile)]
@@ -76,7 +102,7 @@ async fn notify_loading_ready(app: AppHandle) -> Result<(), String> {
             }
         });
     } else {
-        return Err("Loading window not found".to_string());
+        return Err(t!("error-loading-window-not-found"));
     }
 
     Ok(())
@@ -89,15 +115,15 @@ async fn update_window_title(app: AppHandle, title: String) -> Result<(), String
         #[cfg(desktop)]
         main_window
             .set_title(&title)
-            .map_err(|e| format!("Failed to set window title: {e}"))?;
+            .map_err(|e| t!("error-set-window-title", error = e))?;
 
         #[cfg(mobile)]
         {
             // 移动平台可能不支持动态标题更新
-            let _ = title; // 避免未使用变量警告
+            let _ = (main_window, title); // 避免未使用变量警告
         }
     } else {
-        return Err("Main window not found".to_string());
+        return Err(t!("error-main-window-not-found"));
     }
     Ok(())
 }
@@ -109,19 +135,19 @@ async fn create_main_window(app: AppHandle) -> Result<(), String> {
     if app.get_webview_window("main").is_none() {
         #[cfg(desktop)]
         let _main_window = WebviewWindowBuilder::new(&app, "main", WebviewUrl::App("/".into()))
-            .title("Tauri React 模板 - 首页")
+            .title(t!("window-main-title"))
             .inner_size(1280.0, 800.0)
             // .min_inner_size(1280.0, 800.0)
             .center() // 窗口居中显示
             .resizable(true)
             .visible(true)
             .build()
-            .map_err(|e| format!("Failed to create main window: {e}"))?;
+            .map_err(|e| t!("error-create-main-window", error = e))?;
 
         #[cfg(mobile)]
         let _main_window = WebviewWindowBuilder::new(&app, "main", WebviewUrl::App("/".into()))
             .build()
-            .map_err(|e| format!("Failed to create main window: {}", e))?;
+            .map_err(|e| t!("error-create-main-window", error = e))?;
 
         // 在Tauri 2.x中，窗口标题在创建时设置
     }
@@ -135,16 +161,59 @@ pub fn run() {
     let builder = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(
+            // 输出到标准输出与应用日志目录，诊断包会附带日志文件
+            tauri_plugin_log::Builder::new()
+                .level(log::LevelFilter::Info)
+                .max_file_size(1024 * 1024)
+                .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepSome(3))
+                .build(),
+        )
+        .manage(monitor::MonitorState::new())
+        .manage(process::ProcessState::default())
+        .manage(watchdog::WatchdogState::default())
+        .manage(tasks::TaskRegistry::new())
+        .manage(diagnostics::HttpErrorLog::default())
+        .manage(diagnostics::DiagnosticsPreview::default())
+        .manage(system_info::SystemInfoRegistry::new())
+        .manage(metrics::MetricsState::default())
+        .manage(alerts::AlertState::default())
+        .manage(file_watch::WatcherState::new())
+        .manage(file_handle::FileHandleState::new())
+        .manage(open_files::OpenFilesState::default())
         .setup(|app| {
+            // 加载设置并初始化语言
+            let settings = settings::SettingsState::load(app.handle());
+            i18n::init(&settings);
+            app.manage(settings);
+            app.manage(recent::RecentState::load(app.handle()));
+
+            // 排队启动参数中的文件（文件关联也通过参数传入），在 notify_loading_ready 创建主窗口后发送
+            if let Ok(cwd) = std::env::current_dir() {
+                open_files::open(
+                    app.handle(),
+                    open_files::parse_args(std::env::args_os(), &cwd),
+                );
+            }
+
             // 创建菜单 (仅桌面平台)
             let menu = menu::create_menu(app.handle())?;
             app.set_menu(menu)?;
 
+            // 启动资源看门狗
+            watchdog::start(app.handle().clone());
+
+            // 启动历史指标记录
+            metrics::start(app.handle().clone());
+
+            // 启动告警规则检查
+            alerts::start(app.handle().clone());
+
             // 创建 Loading 窗口
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
                 if let Err(e) = create_loading_window(app_handle).await {
-                    eprintln!("Failed to create loading window: {e}");
+                    log::error!("Failed to create loading window: {e}");
                 }
             });
 
@@ -155,10 +224,88 @@ pub fn run() {
             // 系统信息相关
             handlers::get_system_info,
             handlers::get_version_info,
+            handlers::get_host_info,
             handlers::get_disk_info,
+            handlers::get_network_info,
+            // 系统监控相关
+            monitor::monitor_subscribe,
+            monitor::monitor_unsubscribe,
+            monitor::get_monitor_snapshot,
+            metrics::query_metrics,
+            // 告警规则相关
+            alerts::get_alert_rules,
+            alerts::save_alert_rule,
+            alerts::delete_alert_rule,
+            alerts::get_alert_history,
+            alerts::clear_alert_history,
+            // 进程管理相关
+            process::list_processes,
+            process::get_process_details,
+            process::signal_process,
+            // 资源看门狗相关
+            watchdog::get_app_resource_usage,
+            watchdog::get_watchdog_config,
+            watchdog::set_watchdog_config,
             // 文件操作相关
             handlers::get_file_path,
+            handlers::get_file_paths,
+            handlers::save_file_path,
             handlers::get_directory_path,
+            dir_size::scan_directory_size,
+            duplicates::find_duplicates,
+            duplicates::export_duplicates,
+            inspect::inspect_file,
+            // 归档相关
+            archive::create_archive,
+            archive::list_archive,
+            archive::extract_archive,
+            // 图片处理相关
+            images::get_image_thumbnail,
+            images::clear_thumbnail_cache,
+            images::get_image_metadata,
+            images::convert_image,
+            // 授权文件系统访问相关
+            scoped_fs::get_fs_grants,
+            scoped_fs::revoke_fs_grant,
+            scoped_fs::fs_read_file,
+            scoped_fs::fs_write_file,
+            scoped_fs::fs_list_dir,
+            scoped_fs::fs_stat,
+            scoped_fs::fs_mkdir,
+            scoped_fs::fs_rename,
+            scoped_fs::fs_remove,
+            // 文件监听相关
+            file_watch::watch_path,
+            file_watch::unwatch_path,
+            // 大文件分页读取相关
+            file_handle::open_file_handle,
+            file_handle::get_file_handle_info,
+            file_handle::read_file_range,
+            file_handle::read_file_lines,
+            file_handle::close_file_handle,
+            // 文件拖放相关
+            file_drop::get_drop_rules,
+            file_drop::set_drop_rules,
+            // 打开与显示相关
+            launcher::open_path,
+            launcher::open_url,
+            launcher::reveal_path,
+            launcher::pick_application,
+            launcher::get_launcher_apps,
+            launcher::remove_launcher_app,
+            // 最近打开相关
+            recent::get_recent_items,
+            recent::clear_recent_items,
+            recent::set_recent_items_limit,
+            // 诊断相关
+            diagnostics::preview_diagnostics,
+            diagnostics::export_diagnostics,
+            // 后台任务相关
+            tasks::cancel_task,
+            // 语言相关
+            i18n::get_locale,
+            i18n::get_available_locales,
+            i18n::set_locale,
             // 窗口操作相关
             handlers::open_window,
             handlers::app_close,
@@ -173,18 +320,68 @@ pub fn run() {
         ])
         .on_menu_event(|app, event| {
             menu::handle_menu_event(app, &event);
+        })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, position }) =
+                event
+            {
+                file_drop::handle_drop(window, paths.clone(), *position);
+            }
         });
 
     #[cfg(mobile)]
     let builder = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(
+            // 输出到标准输出与应用日志目录，诊断包会附带日志文件
+            tauri_plugin_log::Builder::new()
+                .level(log::LevelFilter::Info)
+                .max_file_size(1024 * 1024)
+                .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepSome(3))
+                .build(),
+        )
+        .manage(monitor::MonitorState::new())
+        .manage(process::ProcessState::default())
+        .manage(watchdog::WatchdogState::default())
+        .manage(tasks::TaskRegistry::new())
+        .manage(diagnostics::HttpErrorLog::default())
+        .manage(diagnostics::DiagnosticsPreview::default())
+        .manage(system_info::SystemInfoRegistry::new())
+        .manage(metrics::MetricsState::default())
+        .manage(alerts::AlertState::default())
+        .manage(file_watch::WatcherState::new())
+        .manage(file_handle::FileHandleState::new())
+        .manage(open_files::OpenFilesState::default())
         .setup(|app| {
+            // 加载设置并初始化语言
+            let settings = settings::SettingsState::load(app.handle());
+            i18n::init(&settings);
+            app.manage(settings);
+            app.manage(recent::RecentState::load(app.handle()));
+
+            // 排队启动参数中的文件（文件关联也通过参数传入），在 notify_loading_ready 创建主窗口后发送
+            if let Ok(cwd) = std::env::current_dir() {
+                open_files::open(
+                    app.handle(),
+                    open_files::parse_args(std::env::args_os(), &cwd),
+                );
+            }
+
+            // 启动资源看门狗
+            watchdog::start(app.handle().clone());
+
+            // 启动历史指标记录
+            metrics::start(app.handle().clone());
+
+            // 启动告警规则检查
+            alerts::start(app.handle().clone());
+
             // 创建 Loading 窗口
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
                 if let Err(e) = create_loading_window(app_handle).await {
-                    eprintln!("Failed to create loading window: {}", e);
+                    log::error!("Failed to create loading window: {e}");
                 }
             });
 
@@ -195,10 +392,88 @@ pub fn run() {
             // 系统信息相关
             handlers::get_system_info,
             handlers::get_version_info,
+            handlers::get_host_info,
             handlers::get_disk_info,
+            handlers::get_network_info,
+            // 系统监控相关
+            monitor::monitor_subscribe,
+            monitor::monitor_unsubscribe,
+            monitor::get_monitor_snapshot,
+            metrics::query_metrics,
+            // 告警规则相关
+            alerts::get_alert_rules,
+            alerts::save_alert_rule,
+            alerts::delete_alert_rule,
+            alerts::get_alert_history,
+            alerts::clear_alert_history,
+            // 进程管理相关
+            process::list_processes,
+            process::get_process_details,
+            process::signal_process,
+            // 资源看门狗相关
+            watchdog::get_app_resource_usage,
+            watchdog::get_watchdog_config,
+            watchdog::set_watchdog_config,
             // 文件操作相关
             handlers::get_file_path,
+            handlers::get_file_paths,
+            handlers::save_file_path,
             handlers::get_directory_path,
+            dir_size::scan_directory_size,
+            duplicates::find_duplicates,
+            duplicates::export_duplicates,
+            inspect::inspect_file,
+            // 归档相关
+            archive::create_archive,
+            archive::list_archive,
+            archive::extract_archive,
+            // 图片处理相关
+            images::get_image_thumbnail,
+            images::clear_thumbnail_cache,
+            images::get_image_metadata,
+            images::convert_image,
+            // 授权文件系统访问相关
+            scoped_fs::get_fs_grants,
+            scoped_fs::revoke_fs_grant,
+            scoped_fs::fs_read_file,
+            scoped_fs::fs_write_file,
+            scoped_fs::fs_list_dir,
+            scoped_fs::fs_stat,
+            scoped_fs::fs_mkdir,
+            scoped_fs::fs_rename,
+            scoped_fs::fs_remove,
+            // 文件监听相关
+            file_watch::watch_path,
+            file_watch::unwatch_path,
+            // 大文件分页读取相关
+            file_handle::open_file_handle,
+            file_handle::get_file_handle_info,
+            file_handle::read_file_range,
+            file_handle::read_file_lines,
+            file_handle::close_file_handle,
+            // 文件拖放相关
+            file_drop::get_drop_rules,
+            file_drop::set_drop_rules,
+            // 打开与显示相关
+            launcher::open_path,
+            launcher::open_url,
+            launcher::reveal_path,
+            launcher::pick_application,
+            launcher::get_launcher_apps,
+            launcher::remove_launcher_app,
+            // 最近打开相关
+            recent::get_recent_items,
+            recent::clear_recent_items,
+            recent::set_recent_items_limit,
+            // 诊断相关
+            diagnostics::preview_diagnostics,
+            diagnostics::export_diagnostics,
+            // 后台任务相关
+            tasks::cancel_task,
+            // 语言相关
+            i18n::get_locale,
+            i18n::get_available_locales,
+            i18n::set_locale,
             // 窗口操作相关
             handlers::open_window,
             handlers::app_close,
@@ -210,9 +485,27 @@ pub fn run() {
             handlers::http_get,
             handlers::http_post,
             handlers::http_request
-        ]);
+        ])
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, position }) =
+                event
+            {
+                file_drop::handle_drop(window, paths.clone(), *position);
+            }
+        });
 
     builder
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|_app, _event| {
+            // macOS 与 iOS 通过系统事件传入文件关联打开的文件
+            #[cfg(any(target_os = "macos", target_os = "ios"))]
+            if let tauri::RunEvent::Opened { urls } = _event {
+                let paths = urls
+                    .into_iter()
+                    .filter_map(|url| url.to_file_path().ok())
+                    .collect();
+                open_files::open(_app, paths);
+            }
+        });
 }
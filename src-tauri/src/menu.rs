@@ -1,6 +1,13 @@
 use tauri::menu::{Menu, MenuBuilder, MenuEvent, MenuItemBuilder, SubmenuBuilder};
 use tauri::{AppHandle, Emitter, Manager};
 
+use crate::build_info::BuildInfo;
+use crate::i18n::t;
+use crate::recent::{RecentKind, RecentState};
+
+/// 最近打开条目的菜单 ID 前缀，后接由路径生成的 `RecentItem::key()`
+const RECENT_ITEM_ID_PREFIX: &str = "recent_item_";
+
 /// 菜单ID枚举
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MenuId {
@@ -10,6 +17,8 @@ pub enum MenuId {
     QuickRestart,
     CloseCurrentWindow,
     CloseAllWindows,
+    ClearRecentItems,
+    ExportDiagnostics,
     About,
     #[cfg(debug_assertions)]
     ToggleDevMode,
@@ -24,6 +33,8 @@ impl MenuId {
             MenuId::QuickRestart => "quick_restart",
             MenuId::CloseCurrentWindow => "close_current_window",
             MenuId::CloseAllWindows => "close_all_windows",
+            MenuId::ClearRecentItems => "clear_recent_items",
+            MenuId::ExportDiagnostics => "export_diagnostics",
             MenuId::About => "about",
             #[cfg(debug_assertions)]
             MenuId::ToggleDevMode => "toggle_dev_mode",
@@ -34,51 +45,92 @@ impl MenuId {
 /// 创建应用菜单
 pub fn create_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
     // 设置子菜单
-    let settings_submenu = SubmenuBuilder::new(app, "设置")
+    let settings_submenu = SubmenuBuilder::new(app, t!("menu-settings"))
         .item(
-            &MenuItemBuilder::new("复制")
+            &MenuItemBuilder::new(t!("menu-copy"))
                 .id(MenuId::Copy.as_str())
                 .accelerator("CmdOrCtrl+C")
                 .build(app)?,
         )
         .item(
-            &MenuItemBuilder::new("粘贴")
+            &MenuItemBuilder::new(t!("menu-paste"))
                 .id(MenuId::Paste.as_str())
                 .accelerator("CmdOrCtrl+V")
                 .build(app)?,
         )
         .item(
-            &MenuItemBuilder::new("全选")
+            &MenuItemBuilder::new(t!("menu-select-all"))
                 .id(MenuId::SelectAll.as_str())
                 .accelerator("CmdOrCtrl+A")
                 .build(app)?,
         )
         .separator()
         .item(
-            &MenuItemBuilder::new("快速重启")
+            &MenuItemBuilder::new(t!("menu-quick-restart"))
                 .id(MenuId::QuickRestart.as_str())
                 .accelerator("CmdOrCtrl+R")
                 .build(app)?,
         )
         .separator()
         .item(
-            &MenuItemBuilder::new("关闭当前窗口")
+            &MenuItemBuilder::new(t!("menu-close-current-window"))
                 .id(MenuId::CloseCurrentWindow.as_str())
                 .accelerator("CmdOrCtrl+W")
                 .build(app)?,
         )
         .item(
-            &MenuItemBuilder::new("关闭所有窗口")
+            &MenuItemBuilder::new(t!("menu-close-all-windows"))
                 .id(MenuId::CloseAllWindows.as_str())
                 .accelerator("CmdOrCtrl+Q")
                 .build(app)?,
         )
         .build()?;
 
+    // 最近打开子菜单（不显示已不存在的条目）
+    let mut recent_builder = SubmenuBuilder::new(app, t!("menu-recent"));
+    let recent_items: Vec<_> = app
+        .state::<RecentState>()
+        .items()
+        .into_iter()
+        .filter(|item| std::path::Path::new(&item.path).exists())
+        .collect();
+    if recent_items.is_empty() {
+        recent_builder = recent_builder.item(
+            &MenuItemBuilder::new(t!("menu-recent-empty"))
+                .enabled(false)
+                .build(app)?,
+        );
+    }
+    for item in &recent_items {
+        let label = match item.kind {
+            RecentKind::File => item.path.clone(),
+            RecentKind::Directory => t!("menu-recent-directory", path = item.path),
+        };
+        recent_builder = recent_builder.item(
+            &MenuItemBuilder::new(label)
+                .id(format!("{RECENT_ITEM_ID_PREFIX}{}", item.key()))
+                .build(app)?,
+        );
+    }
+    let recent_submenu = recent_builder
+        .separator()
+        .item(
+            &MenuItemBuilder::new(t!("menu-clear-recent"))
+                .id(MenuId::ClearRecentItems.as_str())
+                .enabled(!recent_items.is_empty())
+                .build(app)?,
+        )
+        .build()?;
+
     // 帮助子菜单
-    let help_submenu = SubmenuBuilder::new(app, "帮助")
+    let help_submenu = SubmenuBuilder::new(app, t!("menu-help"))
         .item(
-            &MenuItemBuilder::new("关于")
+            &MenuItemBuilder::new(t!("menu-export-diagnostics"))
+                .id(MenuId::ExportDiagnostics.as_str())
+                .build(app)?,
+        )
+        .item(
+            &MenuItemBuilder::new(t!("menu-about"))
                 .id(MenuId::About.as_str())
                 .build(app)?,
         )
@@ -88,14 +140,15 @@ pub fn create_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
     #[cfg_attr(not(debug_assertions), allow(unused_mut))]
     let mut menu_builder = MenuBuilder::new(app)
         .item(&settings_submenu)
+        .item(&recent_submenu)
         .item(&help_submenu);
 
     // 开发模式下添加开发者菜单
     #[cfg(debug_assertions)]
     {
-        let dev_submenu = SubmenuBuilder::new(app, "开发者设置")
+        let dev_submenu = SubmenuBuilder::new(app, t!("menu-developer"))
             .item(
-                &MenuItemBuilder::new("切换到开发者模式")
+                &MenuItemBuilder::new(t!("menu-toggle-dev-mode"))
                     .id(MenuId::ToggleDevMode.as_str())
                     .accelerator("CmdOrCtrl+Alt+I")
                     .build(app)?,
@@ -107,6 +160,13 @@ pub fn create_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
     menu_builder.build()
 }
 
+/// 重新创建并设置应用菜单（语言或最近打开列表变化后调用）
+pub fn refresh(app: &AppHandle) -> tauri::Result<()> {
+    let menu = create_menu(app)?;
+    app.set_menu(menu)?;
+    Ok(())
+}
+
 /// 处理菜单事件
 pub fn handle_menu_event(app: &AppHandle, event: &MenuEvent) {
     let menu_id = event.id().as_ref();
@@ -138,29 +198,46 @@ pub fn handle_menu_event(app: &AppHandle, event: &MenuEvent) {
         "close_all_windows" => {
             app.exit(0);
         }
+        "clear_recent_items" => {
+            let recent = app.state::<RecentState>();
+            if let Err(e) = recent.clear(app) {
+                log::warn!("Failed to clear recent items: {e}");
+            }
+        }
+        id if id.starts_with(RECENT_ITEM_ID_PREFIX) => {
+            crate::recent::open_from_menu(app, &id[RECENT_ITEM_ID_PREFIX.len()..]);
+        }
+        "export_diagnostics" => {
+            tauri::async_runtime::spawn(crate::diagnostics::export_with_preview(app.clone()));
+        }
         "about" => {
             let app_version = app.package_info().version.to_string();
-            let system_info = format!(
-                "版本信息：{}\n系统：{} {}\n架构：{}",
-                app_version,
-                std::env::consts::OS,
-                std::env::consts::ARCH,
-                std::env::consts::FAMILY
-            );
 
             // 显示系统弹窗
             if let Some(_window) = app.get_webview_window("main") {
                 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
-                let dialog_message = format!("template-tauri-react脚手架\n\n{system_info}");
+                let build = BuildInfo::current();
+                let dialog_message = t!(
+                    "about-message",
+                    version = app_version,
+                    commit = build.short_commit(),
+                    build_time = build.build_time,
+                    target = build.target,
+                    profile = build.profile,
+                    tauri_version = build.tauri_version,
+                    os = std::env::consts::OS,
+                    arch = std::env::consts::ARCH,
+                    family = std::env::consts::FAMILY
+                );
 
                 // 使用自定义按钮对话框
                 app.dialog()
                     .message(dialog_message)
-                    .title("关于")
+                    .title(t!("about-title"))
                     .kind(MessageDialogKind::Info)
                     .buttons(MessageDialogButtons::OkCancelCustom(
-                        "查看 GitHub".to_string(),
-                        "确认".to_string(),
+                        t!("about-view-github"),
+                        t!("dialog-ok"),
                     ))
                     .show(move |result| {
                         if result {
@@ -168,7 +245,7 @@ pub fn handle_menu_event(app: &AppHandle, event: &MenuEvent) {
                             if let Err(e) =
                                 open::that("https://github.com/mobaibai/template-tauri-react")
                             {
-                                eprintln!("Failed to open URL: {e}");
+                                log::error!("Failed to open URL: {e}");
                             }
                         }
                         // 如果result为false，用户点击了"确认"按钮，什么都不做
@@ -184,7 +261,7 @@ pub fn handle_menu_event(app: &AppHandle, event: &MenuEvent) {
             }
         }
         _ => {
-            println!("未处理的菜单事件: {menu_id}");
+            log::debug!("未处理的菜单事件: {menu_id}");
         }
     }
 }
@@ -0,0 +1,432 @@
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::monitor::{now_millis, MonitorSample, MonitorState};
+
+/// 原始数据的采样间隔（毫秒）
+const RAW_INTERVAL_MS: u64 = 10_000;
+const MINUTE_MS: u64 = 60_000;
+const HOUR_MS: u64 = 60 * MINUTE_MS;
+/// 自动选择分辨率时单个序列的最大点数
+const MAX_AUTO_POINTS: u64 = 1500;
+/// 指标数据目录（位于应用数据目录）
+const METRICS_DIR: &str = "metrics";
+
+// 数据分辨率
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Resolution {
+    Raw,
+    Minute,
+    Hour,
+}
+
+impl Resolution {
+    const ALL: [Resolution; 3] = [Resolution::Raw, Resolution::Minute, Resolution::Hour];
+
+    /// 每个数据点覆盖的时长
+    fn bucket_ms(self) -> u64 {
+        match self {
+            Resolution::Raw => RAW_INTERVAL_MS,
+            Resolution::Minute => MINUTE_MS,
+            Resolution::Hour => HOUR_MS,
+        }
+    }
+
+    /// 保留时长：原始数据 6 小时，分钟数据 7 天，小时数据 90 天
+    fn retention_ms(self) -> u64 {
+        match self {
+            Resolution::Raw => 6 * HOUR_MS,
+            Resolution::Minute => 7 * 24 * HOUR_MS,
+            Resolution::Hour => 90 * 24 * HOUR_MS,
+        }
+    }
+
+    /// 下一级（更粗）的分辨率
+    fn coarser(self) -> Option<Resolution> {
+        match self {
+            Resolution::Raw => Some(Resolution::Minute),
+            Resolution::Minute => Some(Resolution::Hour),
+            Resolution::Hour => None,
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            Resolution::Raw => "raw.jsonl",
+            Resolution::Minute => "minute.jsonl",
+            Resolution::Hour => "hour.jsonl",
+        }
+    }
+}
+
+// 可查询的指标
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    /// CPU 总使用率（百分比）
+    Cpu,
+    /// 已用内存（字节）
+    Memory,
+    /// 已用交换空间（字节）
+    Swap,
+    /// 1 分钟负载均值
+    Load,
+}
+
+// 一段时间内的统计值
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stat {
+    pub avg: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Stat {
+    fn single(value: f64) -> Self {
+        Self {
+            avg: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    /// 按样本数加权合并
+    fn merge(&mut self, other: &Stat, weight: f64, other_weight: f64) {
+        self.avg = (self.avg * weight + other.avg * other_weight) / (weight + other_weight);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+// 存储的数据点，聚合数据点的时间戳为桶的起始时间
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MetricPoint {
+    timestamp: u64,
+    /// 聚合的原始样本数
+    samples: u32,
+    cpu: Stat,
+    memory: Stat,
+    swap: Stat,
+    load: Stat,
+}
+
+impl MetricPoint {
+    fn from_sample(sample: &MonitorSample) -> Self {
+        Self {
+            timestamp: sample.timestamp,
+            samples: 1,
+            cpu: Stat::single(f64::from(sample.cpu_usage)),
+            memory: Stat::single(sample.memory_used as f64),
+            swap: Stat::single(sample.swap_used as f64),
+            load: Stat::single(sample.load_average.one),
+        }
+    }
+
+    fn merge(&mut self, other: &MetricPoint) {
+        let (weight, other_weight) = (f64::from(self.samples), f64::from(other.samples));
+        self.cpu.merge(&other.cpu, weight, other_weight);
+        self.memory.merge(&other.memory, weight, other_weight);
+        self.swap.merge(&other.swap, weight, other_weight);
+        self.load.merge(&other.load, weight, other_weight);
+        self.samples += other.samples;
+    }
+
+    fn stat(&self, metric: Metric) -> Stat {
+        match metric {
+            Metric::Cpu => self.cpu,
+            Metric::Memory => self.memory,
+            Metric::Swap => self.swap,
+            Metric::Load => self.load,
+        }
+    }
+}
+
+// 查询结果中的数据点
+#[derive(Debug, Serialize)]
+pub struct SeriesPoint {
+    /// Unix 毫秒时间戳（聚合数据为桶的起始时间）
+    timestamp: u64,
+    #[serde(flatten)]
+    stat: Stat,
+}
+
+// 查询结果
+#[derive(Debug, Serialize)]
+pub struct MetricSeries {
+    metric: Metric,
+    resolution: Resolution,
+    points: Vec<SeriesPoint>,
+}
+
+#[derive(Default)]
+struct Tier {
+    points: VecDeque<MetricPoint>,
+    /// 上次压缩后追加到文件的行数
+    appended: usize,
+}
+
+/// 待写入文件的操作，在释放存储锁之后执行
+enum FileWrite {
+    /// 追加一个数据点
+    Append(Resolution, MetricPoint),
+    /// 用给定内容重写整个文件
+    Rewrite(Resolution, String),
+}
+
+/// 指标存储：内存中保存各分辨率的数据，并以 JSON Lines 追加写入文件
+///
+/// 存储只记录待写入的操作（见 `take_writes`），由调用方在锁外执行，查询不会等待磁盘 I/O
+#[derive(Default)]
+struct MetricsStore {
+    tiers: [Tier; 3],
+    /// 分钟与小时分辨率中尚未结束的聚合桶
+    pending: [Option<MetricPoint>; 2],
+    writes: Vec<FileWrite>,
+}
+
+impl MetricsStore {
+    /// 从指标目录加载数据，并根据已有数据重建未结束的聚合桶
+    fn load(dir: &Path) -> Self {
+        let now = now_millis();
+        let mut store = Self::default();
+
+        for resolution in Resolution::ALL {
+            let since = now.saturating_sub(resolution.retention_ms());
+            let points = fs::read_to_string(dir.join(resolution.file_name()))
+                .unwrap_or_default()
+                .lines()
+                .filter_map(|line| serde_json::from_str::<MetricPoint>(line).ok())
+                .filter(|point| point.timestamp >= since)
+                .collect();
+            store.tiers[resolution as usize].points = points;
+        }
+
+        // 先用分钟数据重建小时桶，再用原始数据重建分钟桶（可能补写中断前未落盘的聚合点）
+        for resolution in [Resolution::Minute, Resolution::Raw] {
+            let coarser = resolution.coarser().unwrap();
+            let completed_until = store.tiers[coarser as usize]
+                .points
+                .back()
+                .map_or(0, |point| point.timestamp + coarser.bucket_ms());
+            let replay: Vec<MetricPoint> = store.tiers[resolution as usize]
+                .points
+                .iter()
+                .filter(|point| point.timestamp >= completed_until)
+                .cloned()
+                .collect();
+            for point in &replay {
+                store.roll_up(resolution, point);
+            }
+        }
+
+        // 加载完成后重写文件，丢弃过期数据（重放时补写的聚合点已包含在内）
+        store.writes.clear();
+        for resolution in Resolution::ALL {
+            store.compact(resolution);
+        }
+        write_files(dir, store.take_writes());
+        store
+    }
+
+    /// 取出待写入文件的操作
+    fn take_writes(&mut self) -> Vec<FileWrite> {
+        std::mem::take(&mut self.writes)
+    }
+
+    fn record(&mut self, sample: &MonitorSample) {
+        let point = MetricPoint::from_sample(sample);
+        self.append(Resolution::Raw, point.clone());
+        self.roll_up(Resolution::Raw, &point);
+    }
+
+    /// 将数据点并入上一级分辨率的聚合桶，桶结束时写入并继续向上聚合
+    fn roll_up(&mut self, resolution: Resolution, point: &MetricPoint) {
+        let Some(coarser) = resolution.coarser() else {
+            return;
+        };
+        let bucket = point.timestamp - point.timestamp % coarser.bucket_ms();
+        let pending = &mut self.pending[coarser as usize - 1];
+
+        let completed = match pending {
+            Some(current) if current.timestamp == bucket => {
+                current.merge(point);
+                None
+            }
+            _ => pending.replace(MetricPoint {
+                timestamp: bucket,
+                ..point.clone()
+            }),
+        };
+
+        if let Some(completed) = completed {
+            self.append(coarser, completed.clone());
+            self.roll_up(coarser, &completed);
+        }
+    }
+
+    fn append(&mut self, resolution: Resolution, point: MetricPoint) {
+        let since = point.timestamp.saturating_sub(resolution.retention_ms());
+        let tier = &mut self.tiers[resolution as usize];
+        while tier.points.front().is_some_and(|p| p.timestamp < since) {
+            tier.points.pop_front();
+        }
+
+        self.writes
+            .push(FileWrite::Append(resolution, point.clone()));
+        tier.appended += 1;
+        tier.points.push_back(point);
+
+        // 文件中的过期行超过保留数据量时重写文件
+        if tier.appended > tier.points.len().max(1) {
+            self.compact(resolution);
+        }
+    }
+
+    /// 用内存中的数据重写文件
+    fn compact(&mut self, resolution: Resolution) {
+        let tier = &mut self.tiers[resolution as usize];
+        let content: String = tier
+            .points
+            .iter()
+            .filter_map(|point| serde_json::to_string(point).ok())
+            .map(|line| line + "\n")
+            .collect();
+        self.writes.push(FileWrite::Rewrite(resolution, content));
+        tier.appended = 0;
+    }
+
+    /// 自动选择分辨率：保留范围覆盖查询起点且点数不超过上限的最细分辨率
+    fn auto_resolution(from: u64, to: u64) -> Resolution {
+        let now = now_millis();
+        Resolution::ALL
+            .into_iter()
+            .find(|resolution| {
+                now.saturating_sub(resolution.retention_ms()) <= from
+                    && to.saturating_sub(from) / resolution.bucket_ms() <= MAX_AUTO_POINTS
+            })
+            .unwrap_or(Resolution::Hour)
+    }
+
+    fn query(
+        &self,
+        metric: Metric,
+        from: u64,
+        to: u64,
+        resolution: Resolution,
+    ) -> Vec<SeriesPoint> {
+        self.tiers[resolution as usize]
+            .points
+            .iter()
+            .filter(|point| point.timestamp >= from && point.timestamp <= to)
+            .map(|point| SeriesPoint {
+                timestamp: point.timestamp,
+                stat: point.stat(metric),
+            })
+            .collect()
+    }
+}
+
+/**
+ * 按顺序执行待写入的文件操作
+ */
+fn write_files(dir: &Path, writes: Vec<FileWrite>) {
+    for write in writes {
+        match write {
+            FileWrite::Append(resolution, point) => {
+                if let Err(e) = append_line(&dir.join(resolution.file_name()), &point) {
+                    log::warn!("Failed to persist metrics: {e}");
+                }
+            }
+            FileWrite::Rewrite(resolution, content) => {
+                let path = dir.join(resolution.file_name());
+                let temp = path.with_extension("tmp");
+                let result = fs::create_dir_all(dir)
+                    .and_then(|_| fs::write(&temp, content))
+                    .and_then(|_| fs::rename(&temp, &path));
+                if let Err(e) = result {
+                    log::warn!("Failed to compact metrics: {e}");
+                }
+            }
+        }
+    }
+}
+
+fn append_line(path: &Path, point: &MetricPoint) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(point)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// 历史指标状态（托管在 Tauri State 中）
+#[derive(Default)]
+pub struct MetricsState(Mutex<MetricsStore>);
+
+/**
+ * 启动历史指标记录：加载已有数据，然后订阅监控采样并持续写入
+ */
+pub fn start(app: AppHandle) {
+    // 加载与写入文件都是阻塞操作，整个记录过程在阻塞线程池中运行
+    tauri::async_runtime::spawn_blocking(move || {
+        let dir = match app.path().app_data_dir() {
+            Ok(dir) => dir.join(METRICS_DIR),
+            Err(e) => {
+                log::error!("Failed to resolve metrics directory: {e}");
+                return;
+            }
+        };
+        let metrics = app.state::<MetricsState>();
+        *metrics.0.lock().unwrap() = MetricsStore::load(&dir);
+
+        // 采样回调持有订阅表锁，写入操作转交给当前线程处理
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        app.state::<MonitorState>().subscribe(
+            RAW_INTERVAL_MS,
+            Box::new(move |sample| tx.send(sample.clone()).is_ok()),
+        );
+
+        while let Some(sample) = rx.blocking_recv() {
+            let writes = {
+                let mut store = metrics.0.lock().unwrap();
+                store.record(&sample);
+                store.take_writes()
+            };
+            write_files(&dir, writes);
+        }
+    });
+}
+
+/**
+ * 查询历史指标（from/to 为 Unix 毫秒时间戳，未指定分辨率时自动选择）
+ */
+#[tauri::command]
+pub async fn query_metrics(
+    state: State<'_, MetricsState>,
+    metric: Metric,
+    from: u64,
+    to: u64,
+    resolution: Option<Resolution>,
+) -> Result<MetricSeries, String> {
+    if from > to {
+        return Err(t!("error-invalid-time-range"));
+    }
+
+    let resolution = resolution.unwrap_or_else(|| MetricsStore::auto_resolution(from, to));
+    let points = state.0.lock().unwrap().query(metric, from, to, resolution);
+
+    Ok(MetricSeries {
+        metric,
+        resolution,
+        points,
+    })
+}
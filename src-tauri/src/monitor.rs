@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+use tauri::ipc::Channel;
+use tauri::State;
+use tokio::sync::Notify;
+
+use crate::i18n::t;
+
+/// 默认采样间隔（毫秒）
+const DEFAULT_INTERVAL_MS: u64 = 1000;
+
+// 负载均值
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+// 系统资源采样结果
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorSample {
+    /// 采样时间（Unix 毫秒时间戳）
+    pub timestamp: u64,
+    /// CPU 总使用率（百分比）
+    pub cpu_usage: f32,
+    /// 每个核心的使用率（百分比）
+    pub cpu_per_core: Vec<f32>,
+    pub memory_total: u64,
+    pub memory_used: u64,
+    pub swap_total: u64,
+    pub swap_used: u64,
+    pub load_average: LoadAverage,
+    /// 系统运行时长（秒）
+    pub uptime: u64,
+}
+
+/// 采样回调，返回 `false` 表示订阅方已失效，需要移除
+///
+/// 回调在持有订阅表锁的情况下执行，不能在回调中再调用 [`MonitorState`] 的订阅方法。
+pub type SampleSink = Box<dyn Fn(&MonitorSample) -> bool + Send + Sync>;
+
+struct Subscriber {
+    interval: Duration,
+    next_due: Instant,
+    sink: SampleSink,
+}
+
+#[derive(Default)]
+struct Subscribers {
+    map: HashMap<u32, Subscriber>,
+    sampler_running: bool,
+}
+
+/// 长期持有的 System 实例，只按需刷新 CPU 与内存
+pub struct SharedSystem {
+    pub system: System,
+    cpu_refreshed_at: Instant,
+}
+
+impl SharedSystem {
+    /// 刷新 CPU 与内存；距离上次 CPU 刷新不足最小间隔时沿用上次的 CPU 数据
+    fn refresh(&mut self) {
+        if self.cpu_refreshed_at.elapsed() >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL {
+            self.system.refresh_cpu_usage();
+            self.cpu_refreshed_at = Instant::now();
+        }
+        self.system.refresh_memory();
+    }
+
+    fn sample(&mut self) -> MonitorSample {
+        self.refresh();
+
+        let load = System::load_average();
+        MonitorSample {
+            timestamp: now_millis(),
+            cpu_usage: self.system.global_cpu_usage(),
+            cpu_per_core: self
+                .system
+                .cpus()
+                .iter()
+                .map(|cpu| cpu.cpu_usage())
+                .collect(),
+            memory_total: self.system.total_memory(),
+            memory_used: self.system.used_memory(),
+            swap_total: self.system.total_swap(),
+            swap_used: self.system.used_swap(),
+            load_average: LoadAverage {
+                one: load.one,
+                five: load.five,
+                fifteen: load.fifteen,
+            },
+            uptime: System::uptime(),
+        }
+    }
+}
+
+/// 系统监控状态（托管在 Tauri State 中）
+///
+/// 所有订阅方共享同一个采样任务：采样任务在第一个订阅到来时启动，
+/// 在最后一个订阅取消后退出。
+pub struct MonitorState {
+    system: Arc<Mutex<SharedSystem>>,
+    subscribers: Arc<Mutex<Subscribers>>,
+    /// 新订阅到来时唤醒采样任务，重新计算等待时间
+    wake: Arc<Notify>,
+    next_id: AtomicU32,
+}
+
+impl MonitorState {
+    pub fn new() -> Self {
+        let system = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::nothing().with_cpu_usage())
+                .with_memory(MemoryRefreshKind::everything()),
+        );
+
+        Self {
+            system: Arc::new(Mutex::new(SharedSystem {
+                system,
+                cpu_refreshed_at: Instant::now(),
+            })),
+            subscribers: Arc::new(Mutex::new(Subscribers::default())),
+            wake: Arc::new(Notify::new()),
+            next_id: AtomicU32::new(1),
+        }
+    }
+
+    /// 在共享的 System 实例上执行操作
+    pub fn with_system<R>(&self, f: impl FnOnce(&mut SharedSystem) -> R) -> R {
+        f(&mut self.system.lock().unwrap())
+    }
+
+    /// 立即采样一次
+    pub fn sample(&self) -> MonitorSample {
+        self.with_system(SharedSystem::sample)
+    }
+
+    /// 注册采样回调，返回订阅 ID
+    pub fn subscribe(&self, interval_ms: u64, sink: SampleSink) -> u32 {
+        let interval = Duration::from_millis(interval_ms).max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.map.insert(
+            id,
+            Subscriber {
+                interval,
+                next_due: Instant::now(),
+                sink,
+            },
+        );
+
+        if !subscribers.sampler_running {
+            subscribers.sampler_running = true;
+            let system = self.system.clone();
+            let subscribers = self.subscribers.clone();
+            let wake = self.wake.clone();
+            tauri::async_runtime::spawn(run_sampler(system, subscribers, wake));
+        } else {
+            // 采样任务可能正按更长的间隔休眠，唤醒后立即为新订阅采样
+            self.wake.notify_one();
+        }
+
+        id
+    }
+
+    /// 取消订阅，订阅不存在时返回 `false`
+    pub fn unsubscribe(&self, id: u32) -> bool {
+        self.subscribers.lock().unwrap().map.remove(&id).is_some()
+    }
+}
+
+impl Default for MonitorState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**
+ * 共享采样任务：按各订阅方的间隔分发采样结果
+ */
+async fn run_sampler(
+    system: Arc<Mutex<SharedSystem>>,
+    subscribers: Arc<Mutex<Subscribers>>,
+    wake: Arc<Notify>,
+) {
+    loop {
+        // 计算距离最近一个订阅到期的等待时间
+        let wait = {
+            let mut subscribers = subscribers.lock().unwrap();
+            match subscribers.map.values().map(|s| s.next_due).min() {
+                Some(next_due) => next_due.saturating_duration_since(Instant::now()),
+                None => {
+                    subscribers.sampler_running = false;
+                    return;
+                }
+            }
+        };
+        tokio::select! {
+            _ = tokio::time::sleep(wait) => {}
+            _ = wake.notified() => continue,
+        }
+
+        let sample = system.lock().unwrap().sample();
+        let now = Instant::now();
+
+        subscribers.lock().unwrap().map.retain(|_, subscriber| {
+            if subscriber.next_due > now {
+                return true;
+            }
+            subscriber.next_due = now + subscriber.interval;
+            (subscriber.sink)(&sample)
+        });
+    }
+}
+
+/**
+ * 当前 Unix 毫秒时间戳
+ */
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/**
+ * 订阅系统资源监控，按间隔通过 Channel 推送采样结果
+ */
+#[tauri::command]
+pub async fn monitor_subscribe(
+    state: State<'_, MonitorState>,
+    interval_ms: Option<u64>,
+    on_sample: Channel<MonitorSample>,
+) -> Result<u32, String> {
+    let interval_ms = interval_ms.unwrap_or(DEFAULT_INTERVAL_MS);
+    let id = state.subscribe(
+        interval_ms,
+        Box::new(move |sample| on_sample.send(sample.clone()).is_ok()),
+    );
+    Ok(id)
+}
+
+/**
+ * 取消系统资源监控订阅
+ */
+#[tauri::command]
+pub async fn monitor_unsubscribe(state: State<'_, MonitorState>, id: u32) -> Result<(), String> {
+    if state.unsubscribe(id) {
+        Ok(())
+    } else {
+        Err(t!("error-monitor-subscription-not-found", id = id))
+    }
+}
+
+/**
+ * 获取一次系统资源快照
+ */
+#[tauri::command]
+pub async fn get_monitor_snapshot(state: State<'_, MonitorState>) -> Result<MonitorSample, String> {
+    Ok(state.sample())
+}
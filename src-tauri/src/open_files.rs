@@ -0,0 +1,123 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Url};
+
+use crate::recent::{self, RecentKind};
+use crate::scoped_fs;
+
+/// 启动参数或文件关联打开的文件通过此事件发送给主窗口
+pub const OPEN_FILES_EVENT: &str = "open-files";
+
+// 打开文件事件
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenFilesEvent {
+    paths: Vec<String>,
+}
+
+#[derive(Default)]
+struct Pending {
+    /// 启动流程是否已结束且主窗口已创建
+    ready: bool,
+    paths: Vec<PathBuf>,
+}
+
+/// 待打开文件队列（托管在 Tauri State 中），主窗口创建前收到的文件在此排队
+#[derive(Default)]
+pub struct OpenFilesState(Mutex<Pending>);
+
+/**
+ * 解析命令行参数中的文件路径：跳过程序路径与 `-` 开头的选项，
+ * 支持 `file://` URL，相对路径按 cwd 解析，只保留存在的路径
+ */
+pub fn parse_args(args: impl IntoIterator<Item = OsString>, cwd: &Path) -> Vec<PathBuf> {
+    args.into_iter()
+        .skip(1)
+        .filter(|arg| !arg.to_string_lossy().starts_with('-'))
+        .filter_map(|arg| {
+            let path = match Url::parse(&arg.to_string_lossy()) {
+                Ok(url) if url.scheme() == "file" => url.to_file_path().ok()?,
+                _ => PathBuf::from(arg),
+            };
+            cwd.join(path).canonicalize().ok()
+        })
+        .collect()
+}
+
+/**
+ * 接受打开的文件：授权给文件系统 API 并记录到最近打开，返回路径字符串
+ */
+fn accept(app: &AppHandle, paths: &[PathBuf]) -> Vec<String> {
+    let mut opened = Vec::with_capacity(paths.len());
+    for path in paths {
+        scoped_fs::grant(app, path);
+        let kind = if path.is_dir() {
+            RecentKind::Directory
+        } else {
+            RecentKind::File
+        };
+        opened.push((path.as_path(), kind));
+    }
+    recent::record_many(app, &opened);
+
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect()
+}
+
+/**
+ * 将文件发送给主窗口
+ */
+fn deliver(app: &AppHandle, paths: Vec<PathBuf>) {
+    if app.get_webview_window("main").is_none() {
+        log::warn!(
+            "Main window not found, dropping {} opened files",
+            paths.len()
+        );
+        return;
+    }
+
+    let event = OpenFilesEvent {
+        paths: accept(app, &paths),
+    };
+    if let Err(e) = app.emit_to("main", OPEN_FILES_EVENT, event) {
+        log::warn!("Failed to emit opened files: {e}");
+    }
+}
+
+/**
+ * 请求打开文件：主窗口已创建时立即发送，否则排队等待
+ */
+pub fn open(app: &AppHandle, paths: Vec<PathBuf>) {
+    if paths.is_empty() {
+        return;
+    }
+
+    let state = app.state::<OpenFilesState>();
+    let mut pending = state.0.lock().unwrap();
+    if pending.ready {
+        drop(pending);
+        deliver(app, paths);
+    } else {
+        pending.paths.extend(paths);
+    }
+}
+
+/**
+ * 启动流程结束（`notify_loading_ready` 创建主窗口之后）调用：以 `open-files` 事件发送排队的文件，
+ * 之后收到的文件直接发送。前端应在主窗口初始化时尽早监听该事件
+ */
+pub fn main_window_ready(app: &AppHandle) {
+    let paths = {
+        let state = app.state::<OpenFilesState>();
+        let mut pending = state.0.lock().unwrap();
+        pending.ready = true;
+        std::mem::take(&mut pending.paths)
+    };
+    if !paths.is_empty() {
+        deliver(app, paths);
+    }
+}
@@ -0,0 +1,298 @@
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use sysinfo::{
+    Pid, Process, ProcessRefreshKind, ProcessesToUpdate, Signal, System, UpdateKind, Users,
+};
+use tauri::{AppHandle, State};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+use crate::i18n::t;
+
+/// 进程浏览器状态（托管在 Tauri State 中）
+///
+/// 使用独立的进程表，完整刷新时不会占用系统监控采样任务的锁。
+#[derive(Default)]
+pub struct ProcessState {
+    system: Arc<Mutex<System>>,
+}
+
+impl ProcessState {
+    /// 在阻塞线程池中操作进程表，避免刷新进程与读取 /proc 阻塞异步运行时
+    async fn with_system<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut System) -> R + Send + 'static,
+    ) -> Result<R, String> {
+        let system = self.system.clone();
+        tauri::async_runtime::spawn_blocking(move || f(&mut system.lock().unwrap()))
+            .await
+            .map_err(|e| t!("error-process-query", error = e))
+    }
+}
+
+// 进程概要信息
+#[derive(Debug, Serialize)]
+pub struct ProcessInfo {
+    pid: u32,
+    parent_pid: Option<u32>,
+    name: String,
+    cmd: Vec<String>,
+    /// CPU 使用率（百分比，多核累计，首次采样为 0）
+    cpu_usage: f32,
+    /// 常驻内存（字节）
+    memory: u64,
+    /// 启动时间（Unix 秒）
+    start_time: u64,
+    user: Option<String>,
+}
+
+// 进程详细信息
+#[derive(Debug, Serialize)]
+pub struct ProcessDetails {
+    #[serde(flatten)]
+    info: ProcessInfo,
+    exe: Option<String>,
+    cwd: Option<String>,
+    status: String,
+    virtual_memory: u64,
+    /// 运行时长（秒）
+    run_time: u64,
+    environ: Vec<String>,
+    thread_count: Option<usize>,
+    open_file_count: Option<usize>,
+    /// 打开的文件列表（仅在系统允许时可用）
+    open_files: Option<Vec<String>>,
+}
+
+impl ProcessInfo {
+    fn from_process(process: &Process, users: &Users) -> Self {
+        Self {
+            pid: process.pid().as_u32(),
+            parent_pid: process.parent().map(|pid| pid.as_u32()),
+            name: process.name().to_string_lossy().to_string(),
+            cmd: process
+                .cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy().to_string())
+                .collect(),
+            cpu_usage: process.cpu_usage(),
+            memory: process.memory(),
+            start_time: process.start_time(),
+            user: process
+                .user_id()
+                .and_then(|uid| users.get_user_by_id(uid))
+                .map(|user| user.name().to_string()),
+        }
+    }
+}
+
+/**
+ * 获取进程列表
+ */
+#[tauri::command]
+pub async fn list_processes(
+    processes: State<'_, ProcessState>,
+) -> Result<Vec<ProcessInfo>, String> {
+    processes
+        .with_system(|system| {
+            let users = Users::new_with_refreshed_list();
+            system.refresh_processes_specifics(
+                ProcessesToUpdate::All,
+                true,
+                ProcessRefreshKind::nothing()
+                    .with_cpu()
+                    .with_memory()
+                    .with_cmd(UpdateKind::OnlyIfNotSet)
+                    .with_user(UpdateKind::OnlyIfNotSet)
+                    .without_tasks(),
+            );
+
+            system
+                .processes()
+                .values()
+                .filter(|process| process.thread_kind().is_none())
+                .map(|process| ProcessInfo::from_process(process, &users))
+                .collect()
+        })
+        .await
+}
+
+/**
+ * 获取单个进程的详细信息
+ */
+#[tauri::command]
+pub async fn get_process_details(
+    processes: State<'_, ProcessState>,
+    pid: u32,
+) -> Result<ProcessDetails, String> {
+    let target = Pid::from_u32(pid);
+
+    processes
+        .with_system(move |system| {
+            let users = Users::new_with_refreshed_list();
+            system.refresh_processes_specifics(
+                ProcessesToUpdate::Some(&[target]),
+                true,
+                ProcessRefreshKind::everything(),
+            );
+
+            let process = system
+                .process(target)
+                .ok_or_else(|| t!("error-process-not-found", pid = pid))?;
+
+            Ok(ProcessDetails {
+                info: ProcessInfo::from_process(process, &users),
+                exe: process.exe().map(|path| path.display().to_string()),
+                cwd: process.cwd().map(|path| path.display().to_string()),
+                status: process.status().to_string(),
+                virtual_memory: process.virtual_memory(),
+                run_time: process.run_time(),
+                environ: process
+                    .environ()
+                    .iter()
+                    .map(|var| var.to_string_lossy().to_string())
+                    .collect(),
+                thread_count: process.tasks().map(|tasks| tasks.len()),
+                open_file_count: process.open_files(),
+                open_files: list_open_files(pid),
+            })
+        })
+        .await?
+}
+
+/**
+ * 向进程发送信号（需用户在确认对话框中确认）
+ *
+ * 返回 `false` 表示用户取消了操作
+ */
+#[tauri::command]
+pub async fn signal_process(
+    app: AppHandle,
+    processes: State<'_, ProcessState>,
+    pid: u32,
+    signal: Option<String>,
+) -> Result<bool, String> {
+    let signal_name = signal.unwrap_or_else(|| "term".to_string());
+    let signal = parse_signal(&signal_name)?;
+    let target = Pid::from_u32(pid);
+
+    if sysinfo::get_current_pid().ok() == Some(target) {
+        return Err(t!("error-signal-self"));
+    }
+
+    // 记录启动时间，确认后用于判断 PID 是否已被其他进程复用
+    let (name, start_time) = processes
+        .with_system(move |system| {
+            refresh_process(system, target);
+            system
+                .process(target)
+                .map(|process| {
+                    (
+                        process.name().to_string_lossy().to_string(),
+                        process.start_time(),
+                    )
+                })
+                .ok_or_else(|| t!("error-process-not-found", pid = pid))
+        })
+        .await??;
+
+    // 弹出确认对话框
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .message(t!(
+            "process-signal-message",
+            name = name,
+            pid = pid,
+            signal = signal_name
+        ))
+        .title(t!("process-signal-title"))
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            t!("dialog-confirm"),
+            t!("dialog-cancel"),
+        ))
+        .show(move |confirmed| {
+            let _ = tx.send(confirmed);
+        });
+
+    let confirmed = rx
+        .await
+        .map_err(|e| t!("error-receive-confirmation", error = e))?;
+    if !confirmed {
+        return Ok(false);
+    }
+
+    // 确认对话框可能停留很久，发送前重新读取进程并核对启动时间
+    processes
+        .with_system(move |system| {
+            refresh_process(system, target);
+            let process = system
+                .process(target)
+                .ok_or_else(|| t!("error-process-not-found", pid = pid))?;
+            if process.start_time() != start_time {
+                return Err(t!("error-process-changed", pid = pid));
+            }
+
+            match process.kill_with(signal) {
+                Some(true) => Ok(true),
+                Some(false) => Err(t!("error-send-signal", signal = signal_name)),
+                None => Err(t!(
+                    "error-signal-unsupported-platform",
+                    signal = signal_name
+                )),
+            }
+        })
+        .await?
+}
+
+/**
+ * 只刷新指定进程（已退出的进程会被移除）
+ */
+fn refresh_process(system: &mut System, pid: Pid) {
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing(),
+    );
+}
+
+/**
+ * 解析信号名称
+ */
+fn parse_signal(name: &str) -> Result<Signal, String> {
+    let signal = match name.to_lowercase().trim_start_matches("sig") {
+        "term" => Signal::Term,
+        "kill" => Signal::Kill,
+        "int" | "interrupt" => Signal::Interrupt,
+        "hup" | "hangup" => Signal::Hangup,
+        "quit" => Signal::Quit,
+        "stop" => Signal::Stop,
+        "cont" | "continue" => Signal::Continue,
+        "usr1" | "user1" => Signal::User1,
+        "usr2" | "user2" => Signal::User2,
+        _ => return Err(t!("error-unsupported-signal", signal = name)),
+    };
+    Ok(signal)
+}
+
+/**
+ * 列出进程打开的文件（目前仅支持 Linux，无权限时返回 None）
+ */
+fn list_open_files(pid: u32) -> Option<Vec<String>> {
+    #[cfg(target_os = "linux")]
+    {
+        let entries = std::fs::read_dir(format!("/proc/{pid}/fd")).ok()?;
+        let files = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| std::fs::read_link(entry.path()).ok())
+            .map(|target| target.display().to_string())
+            .collect();
+        Some(files)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid; // 避免未使用变量警告
+        None
+    }
+}
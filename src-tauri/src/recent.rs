@@ -0,0 +1,250 @@
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::monitor::now_millis;
+
+/// 最近打开列表文件名（位于应用数据目录）
+const RECENT_FILE: &str = "recent.json";
+/// 默认最多保留的条目数
+const DEFAULT_MAX_ITEMS: usize = 10;
+/// 条目数上限
+const MAX_ITEMS_LIMIT: usize = 50;
+/// 从菜单打开最近条目时发送给当前焦点窗口的事件名
+#[cfg(desktop)]
+pub const OPEN_RECENT_ITEM_EVENT: &str = "open-recent-item";
+
+// 最近打开条目类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecentKind {
+    File,
+    Directory,
+}
+
+// 最近打开条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentItem {
+    pub path: String,
+    pub kind: RecentKind,
+    /// 最近一次打开时间（Unix 毫秒）
+    pub opened_at: u64,
+}
+
+// 最近打开列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecentItems {
+    pub max_items: usize,
+    /// 按最近打开时间倒序排列
+    pub items: Vec<RecentItem>,
+}
+
+impl RecentItem {
+    /// 由路径生成的稳定标识，用作菜单项 ID（列表顺序变化后仍指向同一条目）
+    #[cfg(desktop)]
+    pub fn key(&self) -> String {
+        blake3::hash(self.path.as_bytes()).to_hex()[..16].to_string()
+    }
+}
+
+impl Default for RecentItems {
+    fn default() -> Self {
+        Self {
+            max_items: DEFAULT_MAX_ITEMS,
+            items: Vec::new(),
+        }
+    }
+}
+
+/// 最近打开列表状态（托管在 Tauri State 中）
+#[derive(Default)]
+pub struct RecentState(Mutex<RecentItems>);
+
+impl RecentState {
+    /// 从应用数据目录加载列表，文件不存在或无法解析时使用默认值
+    pub fn load(app: &AppHandle) -> Self {
+        let recent = recent_path(app)
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self(Mutex::new(recent))
+    }
+
+    /// 当前条目（不检查是否存在）
+    pub fn items(&self) -> Vec<RecentItem> {
+        self.0.lock().unwrap().items.clone()
+    }
+
+    /// 获取列表，同时移除已不存在的文件与目录
+    pub fn get(&self, app: &AppHandle) -> RecentItems {
+        let has_missing = self
+            .items()
+            .iter()
+            .any(|item| !Path::new(&item.path).exists());
+        if has_missing {
+            let result = self.update(app, |recent| {
+                recent.items.retain(|item| Path::new(&item.path).exists());
+            });
+            if let Err(e) = result {
+                log::warn!("Failed to save recent items: {e}");
+            }
+            refresh_menu(app);
+        }
+        self.0.lock().unwrap().clone()
+    }
+
+    /// 清空列表并刷新菜单
+    pub fn clear(&self, app: &AppHandle) -> Result<(), String> {
+        self.update(app, |recent| recent.items.clear())?;
+        refresh_menu(app);
+        Ok(())
+    }
+
+    /// 修改列表并写回文件
+    fn update(&self, app: &AppHandle, f: impl FnOnce(&mut RecentItems)) -> Result<(), String> {
+        let mut recent = self.0.lock().unwrap();
+        f(&mut recent);
+        let max_items = recent.max_items;
+        recent.items.truncate(max_items);
+
+        let path = recent_path(app)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| t!("error-save-recent-items", error = e))?;
+        }
+        let content = serde_json::to_string_pretty(&*recent)
+            .map_err(|e| t!("error-save-recent-items", error = e))?;
+        std::fs::write(path, content).map_err(|e| t!("error-save-recent-items", error = e))
+    }
+}
+
+/**
+ * 最近打开列表文件路径
+ */
+fn recent_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(RECENT_FILE))
+        .map_err(|e| t!("error-app-data-dir", error = e))
+}
+
+/**
+ * 重建原生菜单以显示最新的最近打开列表
+ */
+fn refresh_menu(app: &AppHandle) {
+    #[cfg(desktop)]
+    if let Err(e) = crate::menu::refresh(app) {
+        log::warn!("Failed to refresh menu: {e}");
+    }
+
+    #[cfg(mobile)]
+    let _ = app; // 移动平台没有原生菜单
+}
+
+/**
+ * 记录一次打开（移到列表最前面）
+ */
+pub fn record(app: &AppHandle, path: &Path, kind: RecentKind) {
+    record_many(app, &[(path, kind)]);
+}
+
+/**
+ * 记录一次同时打开的多个条目（按给定顺序排在列表最前面），只写一次文件并刷新一次菜单
+ */
+pub fn record_many(app: &AppHandle, entries: &[(&Path, RecentKind)]) {
+    if entries.is_empty() {
+        return;
+    }
+
+    let opened_at = now_millis();
+    let result = app.state::<RecentState>().update(app, |recent| {
+        for (path, kind) in entries.iter().rev() {
+            let path = path.display().to_string();
+            recent.items.retain(|item| item.path != path);
+            recent.items.insert(
+                0,
+                RecentItem {
+                    path,
+                    kind: *kind,
+                    opened_at,
+                },
+            );
+        }
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to save recent items: {e}");
+    }
+    refresh_menu(app);
+}
+
+/**
+ * 菜单入口：将标识为 `key` 的条目发送给当前焦点窗口，文件已不存在时从列表中移除
+ */
+#[cfg(desktop)]
+pub fn open_from_menu(app: &AppHandle, key: &str) {
+    use tauri::Emitter;
+
+    let recent = app.state::<RecentState>();
+    let Some(item) = recent.items().into_iter().find(|item| item.key() == key) else {
+        return;
+    };
+    if !Path::new(&item.path).exists() {
+        recent.get(app);
+        return;
+    }
+
+    let target = app
+        .webview_windows()
+        .into_values()
+        .find(|window| window.is_focused().unwrap_or(false))
+        .or_else(|| app.get_webview_window("main"));
+    if let Some(window) = target {
+        if let Err(e) = app.emit_to(window.label(), OPEN_RECENT_ITEM_EVENT, &item) {
+            log::warn!("Failed to emit recent item: {e}");
+        }
+    }
+    record(app, Path::new(&item.path), item.kind);
+}
+
+/**
+ * 获取最近打开的文件与目录（已不存在的条目会被移除）
+ */
+#[tauri::command]
+pub async fn get_recent_items(
+    app: AppHandle,
+    recent: State<'_, RecentState>,
+) -> Result<RecentItems, String> {
+    Ok(recent.get(&app))
+}
+
+/**
+ * 清空最近打开列表
+ */
+#[tauri::command]
+pub async fn clear_recent_items(
+    app: AppHandle,
+    recent: State<'_, RecentState>,
+) -> Result<(), String> {
+    recent.clear(&app)
+}
+
+/**
+ * 设置最近打开列表的最大条目数
+ */
+#[tauri::command]
+pub async fn set_recent_items_limit(
+    app: AppHandle,
+    recent: State<'_, RecentState>,
+    max_items: usize,
+) -> Result<(), String> {
+    if max_items == 0 || max_items > MAX_ITEMS_LIMIT {
+        return Err(t!("error-invalid-recent-limit", max = MAX_ITEMS_LIMIT));
+    }
+    recent.update(&app, |recent| recent.max_items = max_items)?;
+    refresh_menu(&app);
+    Ok(())
+}
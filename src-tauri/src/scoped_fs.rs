@@ -0,0 +1,408 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::i18n::t;
+use crate::settings::SettingsState;
+
+// 文件或目录信息
+#[derive(Debug, Serialize)]
+pub struct FsEntry {
+    name: String,
+    path: String,
+    is_dir: bool,
+    is_file: bool,
+    is_symlink: bool,
+    /// 文件大小（字节）
+    size: u64,
+    /// 修改时间（Unix 毫秒）
+    modified: Option<u64>,
+    readonly: bool,
+}
+
+impl FsEntry {
+    fn from_path(path: &Path) -> Result<Self, String> {
+        let metadata = fs::symlink_metadata(path).map_err(|e| t!("error-fs-io", error = e))?;
+        let is_symlink = metadata.file_type().is_symlink();
+        // 符号链接按目标的类型与大小返回
+        let metadata = if is_symlink {
+            fs::metadata(path).unwrap_or(metadata)
+        } else {
+            metadata
+        };
+
+        Ok(Self {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path: path.display().to_string(),
+            is_dir: metadata.is_dir(),
+            is_file: metadata.is_file(),
+            is_symlink,
+            size: metadata.len(),
+            modified: metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as u64),
+            readonly: metadata.permissions().readonly(),
+        })
+    }
+}
+
+/**
+ * 将用户通过对话框选择的文件或目录登记为授权根路径并保存到设置
+ */
+pub fn grant(app: &AppHandle, path: &Path) {
+    let Some(path) = normalize(path) else {
+        log::warn!("Failed to grant access to {}", path.display());
+        return;
+    };
+    let path = path.display().to_string();
+
+    let result = app.state::<SettingsState>().update(app, |settings| {
+        if !settings.fs_grants.contains(&path) {
+            settings.fs_grants.push(path.clone());
+        }
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to persist file system grant: {e}");
+    }
+}
+
+/**
+ * 规范化路径：解析最近一级已存在的祖先目录（包括符号链接），
+ * 其余部分只能是普通路径分量，不允许 `..`
+ */
+fn normalize(path: &Path) -> Option<PathBuf> {
+    if !path.is_absolute() {
+        return None;
+    }
+
+    let mut existing = path;
+    let mut rest = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            let mut resolved = canonical;
+            for component in rest.iter().rev() {
+                resolved.push(component);
+            }
+            return Some(resolved);
+        }
+        match existing.components().next_back()? {
+            Component::Normal(name) => rest.push(name.to_os_string()),
+            _ => return None,
+        }
+        existing = existing.parent()?;
+    }
+}
+
+/**
+ * 路径是否位于某个授权根路径之内（按路径分量比较，`/a/bc` 不属于 `/a/b`）
+ */
+pub(crate) fn is_granted(grants: &[String], resolved: &Path) -> bool {
+    grants.iter().any(|root| resolved.starts_with(root))
+}
+
+/**
+ * 路径是否为授权根路径本身
+ */
+fn is_grant_root(grants: &[String], resolved: &Path) -> bool {
+    grants.iter().any(|root| resolved == Path::new(root))
+}
+
+/**
+ * 检查规范化后的路径是否位于某个授权根路径之内
+ */
+fn check_granted(
+    settings: &SettingsState,
+    resolved: PathBuf,
+    path: &str,
+) -> Result<PathBuf, String> {
+    if is_granted(&settings.get().fs_grants, &resolved) {
+        Ok(resolved)
+    } else {
+        Err(t!("error-fs-access-denied", path = path))
+    }
+}
+
+/**
+ * 解析并检查路径，返回规范化后的路径
+ */
+pub(crate) fn resolve(settings: &SettingsState, path: &str) -> Result<PathBuf, String> {
+    let resolved =
+        normalize(Path::new(path)).ok_or_else(|| t!("error-fs-invalid-path", path = path))?;
+    check_granted(settings, resolved, path)
+}
+
+/**
+ * 解析并检查路径，但不解析最后一级的符号链接，返回指向条目本身（而非链接目标）的路径
+ */
+pub(crate) fn resolve_link(settings: &SettingsState, path: &str) -> Result<PathBuf, String> {
+    let invalid = || t!("error-fs-invalid-path", path = path);
+    let target = Path::new(path);
+    let Some(Component::Normal(file_name)) = target.components().next_back() else {
+        return Err(invalid());
+    };
+    let parent = target.parent().and_then(normalize).ok_or_else(invalid)?;
+    check_granted(settings, parent.join(file_name), path)
+}
+
+/**
+ * 解析用于删除或重命名的路径：不解析最后一级的符号链接（操作链接本身），
+ * 并禁止操作授权根路径本身
+ */
+fn resolve_entry(settings: &SettingsState, path: &str) -> Result<PathBuf, String> {
+    let resolved = resolve_link(settings, path)?;
+
+    if is_grant_root(&settings.get().fs_grants, &resolved) {
+        return Err(t!("error-fs-root-protected", path = path));
+    }
+    Ok(resolved)
+}
+
+/**
+ * 获取已授权的根路径
+ */
+#[tauri::command]
+pub async fn get_fs_grants(settings: State<'_, SettingsState>) -> Result<Vec<String>, String> {
+    Ok(settings.get().fs_grants)
+}
+
+/**
+ * 撤销授权
+ */
+#[tauri::command]
+pub async fn revoke_fs_grant(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    path: String,
+) -> Result<(), String> {
+    if !settings.get().fs_grants.contains(&path) {
+        return Err(t!("error-fs-grant-not-found", path = path));
+    }
+    settings.update(&app, |settings| {
+        settings.fs_grants.retain(|root| *root != path);
+    })
+}
+
+/**
+ * 读取文件（encoding 为 utf8 或 base64，默认 utf8）
+ */
+#[tauri::command]
+pub async fn fs_read_file(
+    settings: State<'_, SettingsState>,
+    path: String,
+    encoding: Option<String>,
+) -> Result<String, String> {
+    let path = resolve(&settings, &path)?;
+    let bytes = fs::read(path).map_err(|e| t!("error-fs-io", error = e))?;
+
+    match encoding.as_deref().unwrap_or("utf8") {
+        "utf8" => String::from_utf8(bytes).map_err(|e| t!("error-fs-io", error = e)),
+        "base64" => Ok(general_purpose::STANDARD.encode(bytes)),
+        other => Err(t!("error-unsupported-encoding", encoding = other)),
+    }
+}
+
+/**
+ * 写入文件（encoding 为 utf8 或 base64，默认 utf8；append 为 true 时追加）
+ */
+#[tauri::command]
+pub async fn fs_write_file(
+    settings: State<'_, SettingsState>,
+    path: String,
+    contents: String,
+    encoding: Option<String>,
+    append: Option<bool>,
+) -> Result<(), String> {
+    let path = resolve(&settings, &path)?;
+    let bytes = match encoding.as_deref().unwrap_or("utf8") {
+        "utf8" => contents.into_bytes(),
+        "base64" => general_purpose::STANDARD
+            .decode(contents)
+            .map_err(|e| t!("error-fs-io", error = e))?,
+        other => return Err(t!("error-unsupported-encoding", encoding = other)),
+    };
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append.unwrap_or(false))
+        .truncate(!append.unwrap_or(false))
+        .open(path)
+        .map_err(|e| t!("error-fs-io", error = e))?;
+    file.write_all(&bytes)
+        .map_err(|e| t!("error-fs-io", error = e))
+}
+
+/**
+ * 列出目录内容
+ */
+#[tauri::command]
+pub async fn fs_list_dir(
+    settings: State<'_, SettingsState>,
+    path: String,
+) -> Result<Vec<FsEntry>, String> {
+    let path = resolve(&settings, &path)?;
+    let entries = fs::read_dir(path).map_err(|e| t!("error-fs-io", error = e))?;
+
+    let mut result: Vec<FsEntry> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| FsEntry::from_path(&entry.path()).ok())
+        .collect();
+    result.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    Ok(result)
+}
+
+/**
+ * 获取文件或目录信息
+ */
+#[tauri::command]
+pub async fn fs_stat(settings: State<'_, SettingsState>, path: String) -> Result<FsEntry, String> {
+    let path = resolve(&settings, &path)?;
+    FsEntry::from_path(&path)
+}
+
+/**
+ * 创建目录（recursive 为 true 时创建所有缺失的上级目录）
+ */
+#[tauri::command]
+pub async fn fs_mkdir(
+    settings: State<'_, SettingsState>,
+    path: String,
+    recursive: Option<bool>,
+) -> Result<(), String> {
+    let path = resolve(&settings, &path)?;
+    let result = if recursive.unwrap_or(false) {
+        fs::create_dir_all(path)
+    } else {
+        fs::create_dir(path)
+    };
+    result.map_err(|e| t!("error-fs-io", error = e))
+}
+
+/**
+ * 重命名或移动文件与目录（源与目标都必须位于授权范围内，且都不能是授权根路径本身）
+ */
+#[tauri::command]
+pub async fn fs_rename(
+    settings: State<'_, SettingsState>,
+    from: String,
+    to: String,
+) -> Result<(), String> {
+    let from = resolve_entry(&settings, &from)?;
+    let to = resolve_entry(&settings, &to)?;
+    fs::rename(from, to).map_err(|e| t!("error-fs-io", error = e))
+}
+
+/**
+ * 删除文件或目录（删除非空目录需要 recursive 为 true）
+ */
+#[tauri::command]
+pub async fn fs_remove(
+    settings: State<'_, SettingsState>,
+    path: String,
+    recursive: Option<bool>,
+) -> Result<(), String> {
+    let path = resolve_entry(&settings, &path)?;
+    let metadata = fs::symlink_metadata(&path).map_err(|e| t!("error-fs-io", error = e))?;
+
+    let result = if !metadata.is_dir() {
+        fs::remove_file(path)
+    } else if recursive.unwrap_or(false) {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_dir(path)
+    };
+    result.map_err(|e| t!("error-fs-io", error = e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在系统临时目录下创建测试专用的空目录，返回规范化后的路径
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("scoped-fs-test-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.canonicalize().unwrap()
+    }
+
+    fn grants(paths: &[&Path]) -> Vec<String> {
+        paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn normalize_rejects_relative_paths() {
+        assert_eq!(normalize(Path::new("relative/file.txt")), None);
+    }
+
+    #[test]
+    fn normalize_keeps_missing_components() {
+        let dir = temp_dir("missing");
+        assert_eq!(
+            normalize(&dir.join("new/file.txt")),
+            Some(dir.join("new").join("file.txt"))
+        );
+    }
+
+    #[test]
+    fn normalize_rejects_parent_in_missing_components() {
+        let dir = temp_dir("parent");
+        assert_eq!(normalize(&dir.join("missing/../../escape.txt")), None);
+        assert_eq!(normalize(&dir.join("missing/..")), None);
+    }
+
+    #[test]
+    fn normalize_resolves_parent_in_existing_components() {
+        let dir = temp_dir("existing-parent");
+        fs::create_dir(dir.join("sub")).unwrap();
+        assert_eq!(
+            normalize(&dir.join("sub/../file.txt")),
+            Some(dir.join("file.txt"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn normalize_resolves_symlinked_ancestors() {
+        let dir = temp_dir("symlink");
+        fs::create_dir(dir.join("granted")).unwrap();
+        fs::create_dir(dir.join("outside")).unwrap();
+        std::os::unix::fs::symlink(dir.join("outside"), dir.join("granted/link")).unwrap();
+
+        let resolved = normalize(&dir.join("granted/link/new.txt")).unwrap();
+        assert_eq!(resolved, dir.join("outside").join("new.txt"));
+        assert!(!is_granted(&grants(&[&dir.join("granted")]), &resolved));
+    }
+
+    #[test]
+    fn granted_compares_whole_components() {
+        let grants = grants(&[Path::new("/a/b")]);
+        assert!(is_granted(&grants, Path::new("/a/b")));
+        assert!(is_granted(&grants, Path::new("/a/b/c.txt")));
+        assert!(!is_granted(&grants, Path::new("/a/bc")));
+        assert!(!is_granted(&grants, Path::new("/a/bc/c.txt")));
+        assert!(!is_granted(&grants, Path::new("/a")));
+    }
+
+    #[test]
+    fn grant_roots_are_protected() {
+        let grants = grants(&[Path::new("/a/b")]);
+        assert!(is_grant_root(&grants, Path::new("/a/b")));
+        assert!(!is_grant_root(&grants, Path::new("/a/b/c")));
+        assert!(!is_grant_root(&grants, Path::new("/a")));
+    }
+}
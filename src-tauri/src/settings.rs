@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::file_drop::DropRules;
+use crate::i18n::t;
+use crate::watchdog::WatchdogConfig;
+
+/// 设置文件名（位于应用配置目录）
+const SETTINGS_FILE: &str = "settings.json";
+
+// 应用设置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// 界面语言，为空时跟随系统
+    pub locale: Option<String>,
+    /// 用户通过对话框授权访问的文件与目录（规范化后的绝对路径）
+    pub fs_grants: Vec<String>,
+    /// 文件拖放的接受规则
+    pub file_drop: DropRules,
+    /// 用户通过对话框选择、允许用来打开文件与 URL 的应用（规范化后的绝对路径）
+    pub launcher_apps: Vec<String>,
+    /// 资源看门狗配置
+    pub watchdog: WatchdogConfig,
+}
+
+/// 应用设置状态（托管在 Tauri State 中）
+#[derive(Default)]
+pub struct SettingsState(Mutex<Settings>);
+
+impl SettingsState {
+    /// 从应用配置目录加载设置，文件不存在或无法解析时使用默认值
+    pub fn load(app: &AppHandle) -> Self {
+        let settings = settings_path(app)
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self(Mutex::new(settings))
+    }
+
+    pub fn get(&self) -> Settings {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// 修改设置并写回文件
+    pub fn update(&self, app: &AppHandle, f: impl FnOnce(&mut Settings)) -> Result<(), String> {
+        let mut settings = self.0.lock().unwrap();
+        f(&mut settings);
+
+        let path = settings_path(app)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| t!("error-save-settings", error = e))?;
+        }
+        let content = serde_json::to_string_pretty(&*settings)
+            .map_err(|e| t!("error-save-settings", error = e))?;
+        std::fs::write(path, content).map_err(|e| t!("error-save-settings", error = e))
+    }
+}
+
+/**
+ * 设置文件路径
+ */
+fn settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(SETTINGS_FILE))
+        .map_err(|e| t!("error-app-config-dir", error = e))
+}
@@ -0,0 +1,170 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::i18n::t;
+use crate::monitor::MonitorState;
+
+/// 提供者默认超时时间
+const DEFAULT_PROVIDER_TIMEOUT: Duration = Duration::from_secs(3);
+
+// 系统信息结构体
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemInfo {
+    /// 分组标识（由注册表按提供者填写）
+    pub group: String,
+    /// 分组显示名称
+    pub group_name: String,
+    pub key: String,
+    pub name: String,
+    pub value: serde_json::Value,
+}
+
+impl SystemInfo {
+    pub fn new(
+        key: impl Into<String>,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        Self {
+            group: String::new(),
+            group_name: String::new(),
+            key: key.into(),
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// 提供者返回的异步结果
+pub type ProviderFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Vec<SystemInfo>, String>> + Send + 'a>>;
+
+/// 系统信息提供者，各产品变体可注册自己的提供者以追加信息行
+pub trait SystemInfoProvider: Send + Sync + 'static {
+    /// 分组标识，如 `system`、`license`
+    fn group(&self) -> &str;
+
+    /// 分组显示名称（已本地化）
+    fn group_name(&self) -> String;
+
+    /// 单次收集的超时时间，超时的提供者会被跳过
+    fn timeout(&self) -> Duration {
+        DEFAULT_PROVIDER_TIMEOUT
+    }
+
+    /// 收集该分组的信息行
+    fn collect<'a>(&'a self, app: &'a AppHandle) -> ProviderFuture<'a>;
+}
+
+/// 系统信息提供者注册表（托管在 Tauri State 中）
+pub struct SystemInfoRegistry {
+    providers: RwLock<Vec<Arc<dyn SystemInfoProvider>>>,
+}
+
+impl SystemInfoRegistry {
+    /// 创建包含内置系统信息提供者的注册表
+    pub fn new() -> Self {
+        let registry = Self {
+            providers: RwLock::new(Vec::new()),
+        };
+        registry.register(BuiltinProvider);
+        registry
+    }
+
+    /// 注册提供者，同一分组标识的提供者会被替换
+    pub fn register(&self, provider: impl SystemInfoProvider) {
+        let mut providers = self.providers.write().unwrap();
+        providers.retain(|existing| existing.group() != provider.group());
+        providers.push(Arc::new(provider));
+    }
+
+    /// 并发调用所有提供者，按注册顺序汇总结果；失败或超时的提供者只记录日志
+    pub async fn collect(&self, app: &AppHandle) -> Vec<SystemInfo> {
+        let providers = self.providers.read().unwrap().clone();
+
+        let handles: Vec<_> = providers
+            .into_iter()
+            .map(|provider| {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    let result = tokio::time::timeout(provider.timeout(), provider.collect(&app))
+                        .await
+                        .unwrap_or_else(|_| Err(t!("error-provider-timeout")));
+                    (provider, result)
+                })
+            })
+            .collect();
+
+        let mut system_info = Vec::new();
+        for handle in handles {
+            let Ok((provider, result)) = handle.await else {
+                continue;
+            };
+            match result {
+                Ok(rows) => {
+                    let group_name = provider.group_name();
+                    system_info.extend(rows.into_iter().map(|row| SystemInfo {
+                        group: provider.group().to_string(),
+                        group_name: group_name.clone(),
+                        ..row
+                    }));
+                }
+                Err(e) => {
+                    log::warn!("System info provider {} failed: {e}", provider.group());
+                }
+            }
+        }
+
+        system_info
+    }
+}
+
+impl Default for SystemInfoRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 内置提供者：架构、操作系统、CPU 核心数与总内存
+struct BuiltinProvider;
+
+impl SystemInfoProvider for BuiltinProvider {
+    fn group(&self) -> &str {
+        "system"
+    }
+
+    fn group_name(&self) -> String {
+        t!("system-info-group-system")
+    }
+
+    fn collect<'a>(&'a self, app: &'a AppHandle) -> ProviderFuture<'a> {
+        Box::pin(async move {
+            let arch = std::env::consts::ARCH.to_string();
+            let platform = std::env::consts::OS.to_string();
+            let cpu_count = num_cpus::get();
+
+            // 获取总内存（字节），复用监控模块中长期持有的 System 实例
+            let total_memory = app.state::<MonitorState>().with_system(|shared| {
+                shared.system.refresh_memory();
+                shared.system.total_memory()
+            });
+            let total_memory_gb = (total_memory as f64 / 1024.0 / 1024.0 / 1024.0).round() as u64;
+
+            Ok(vec![
+                SystemInfo::new("arch", t!("system-info-arch"), arch),
+                SystemInfo::new("platform", t!("system-info-platform"), platform),
+                SystemInfo::new("cpu_count", t!("system-info-cpu-count"), cpu_count),
+                SystemInfo::new(
+                    "total_memory",
+                    t!("system-info-total-memory"),
+                    format!("{total_memory_gb} GB"),
+                ),
+            ])
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tauri::State;
+
+use crate::i18n::t;
+
+/// 后台任务的取消标记
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// 后台任务注册表（托管在 Tauri State 中），用于按任务 ID 取消任务
+pub struct TaskRegistry {
+    next_id: AtomicU32,
+    tasks: Mutex<HashMap<u32, CancelToken>>,
+}
+
+impl TaskRegistry {
+    pub fn new() -> Self {
+        Self {
+            next_id: AtomicU32::new(1),
+            tasks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 登记一个新任务，返回任务 ID 与取消标记
+    pub fn register(&self) -> (u32, CancelToken) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let token = CancelToken::default();
+        self.tasks.lock().unwrap().insert(id, token.clone());
+        (id, token)
+    }
+
+    /// 任务结束后移除登记
+    pub fn finish(&self, id: u32) {
+        self.tasks.lock().unwrap().remove(&id);
+    }
+
+    /// 取消任务，任务不存在（或已结束）时返回 `false`
+    pub fn cancel(&self, id: u32) -> bool {
+        match self.tasks.lock().unwrap().get(&id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for TaskRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**
+ * 取消后台任务
+ */
+#[tauri::command]
+pub async fn cancel_task(tasks: State<'_, TaskRegistry>, task_id: u32) -> Result<(), String> {
+    if tasks.cancel(task_id) {
+        Ok(())
+    } else {
+        Err(t!("error-task-not-found", id = task_id))
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::i18n::t;
+use crate::settings::SettingsState;
+
+/// 资源超限时发送的事件名
+pub const RESOURCE_WARNING_EVENT: &str = "resource-warning";
+
+// 看门狗配置（保存在设置中）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchdogConfig {
+    pub enabled: bool,
+    /// 检查间隔（毫秒）
+    pub interval_ms: u64,
+    /// 单个进程常驻内存上限（MB）
+    pub max_memory_mb: Option<u64>,
+    /// 单个进程 CPU 使用率上限（百分比）
+    pub max_cpu_percent: Option<f32>,
+    pub max_threads: Option<usize>,
+    pub max_open_files: Option<usize>,
+    /// Webview 子进程超限时是否重新加载对应窗口（见 `offending_windows`）
+    pub reload_on_exceed: bool,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_ms: 5000,
+            max_memory_mb: Some(2048),
+            max_cpu_percent: None,
+            max_threads: None,
+            max_open_files: None,
+            reload_on_exceed: false,
+        }
+    }
+}
+
+// 单个进程的资源占用
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessUsage {
+    pid: u32,
+    name: String,
+    /// 是否为应用主进程（否则为 Webview 等子进程）
+    is_main: bool,
+    /// 常驻内存（字节）
+    memory: u64,
+    cpu_usage: f32,
+    thread_count: Option<usize>,
+    open_files: Option<usize>,
+}
+
+// 应用整体资源占用
+#[derive(Debug, Clone, Serialize)]
+pub struct AppResourceUsage {
+    total_memory: u64,
+    total_cpu_usage: f32,
+    processes: Vec<ProcessUsage>,
+}
+
+// 资源超限警告
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceWarning {
+    pid: u32,
+    name: String,
+    metric: String,
+    value: f64,
+    threshold: f64,
+}
+
+/// 看门狗状态（托管在 Tauri State 中）
+#[derive(Default)]
+pub struct WatchdogState {
+    /// 已处于超限状态的 (PID, 指标)，恢复正常前不重复告警
+    exceeded: Mutex<HashSet<(u32, &'static str)>>,
+    /// 看门狗自用的进程表，不与监控共享，避免刷新打乱其他调用方的 CPU 使用率
+    systems: Mutex<WatchdogSystems>,
+}
+
+#[derive(Default)]
+struct WatchdogSystems {
+    /// 只用于发现进程树（不读取 CPU 与内存）
+    tree: System,
+    /// 只刷新应用自身进程的 CPU 与内存
+    usage: System,
+}
+
+/**
+ * 启动看门狗后台任务
+ */
+pub fn start(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let config = app.state::<SettingsState>().get().watchdog;
+            tokio::time::sleep(Duration::from_millis(config.interval_ms.max(1000))).await;
+
+            if config.enabled {
+                // 刷新进程表与读取进程信息会阻塞，放到阻塞线程池中执行
+                let app = app.clone();
+                let _ = tauri::async_runtime::spawn_blocking(move || check(&app, &config)).await;
+            }
+        }
+    });
+}
+
+/**
+ * 检查一次资源占用并发送超限警告
+ */
+fn check(app: &AppHandle, config: &WatchdogConfig) {
+    let watchdog = app.state::<WatchdogState>();
+    let usage = watchdog.collect_usage();
+    let mut exceeded = watchdog.exceeded.lock().unwrap();
+    let mut offending = Vec::new();
+
+    let mut still_exceeded = HashSet::new();
+    for process in &usage.processes {
+        let checks: [(&'static str, Option<f64>, Option<f64>); 4] = [
+            (
+                "memory",
+                Some(process.memory as f64),
+                config.max_memory_mb.map(|mb| mb as f64 * 1024.0 * 1024.0),
+            ),
+            (
+                "cpu",
+                Some(process.cpu_usage as f64),
+                config.max_cpu_percent.map(f64::from),
+            ),
+            (
+                "threads",
+                process.thread_count.map(|n| n as f64),
+                config.max_threads.map(|n| n as f64),
+            ),
+            (
+                "open_files",
+                process.open_files.map(|n| n as f64),
+                config.max_open_files.map(|n| n as f64),
+            ),
+        ];
+
+        for (metric, value, threshold) in checks {
+            let (Some(value), Some(threshold)) = (value, threshold) else {
+                continue;
+            };
+            if value <= threshold {
+                continue;
+            }
+
+            let key = (process.pid, metric);
+            if !exceeded.contains(&key) {
+                let warning = ResourceWarning {
+                    pid: process.pid,
+                    name: process.name.clone(),
+                    metric: metric.to_string(),
+                    value,
+                    threshold,
+                };
+                if let Err(e) = app.emit(RESOURCE_WARNING_EVENT, &warning) {
+                    log::warn!("Failed to emit resource warning: {e}");
+                }
+                offending.push(process.is_main);
+            }
+            still_exceeded.insert(key);
+        }
+    }
+    *exceeded = still_exceeded;
+
+    drop(exceeded);
+
+    if config.reload_on_exceed && offending.contains(&false) {
+        for window in offending_windows(app) {
+            if let Err(e) = window.reload() {
+                log::error!("Failed to reload window {}: {e}", window.label());
+            }
+        }
+    }
+}
+
+/**
+ * 确定需要重新加载的窗口（尽力而为）
+ *
+ * 只有 Webview 子进程超限时才重新加载，主进程超限无法通过重新加载缓解。
+ * 平台 API 无法把 Webview 子进程对应到具体窗口，因此只有一个窗口时重新加载该窗口，
+ * 有多个窗口时重新加载当前获得焦点的窗口，都无法确定时不重新加载
+ */
+fn offending_windows(app: &AppHandle) -> Vec<tauri::WebviewWindow> {
+    let windows: Vec<_> = app.webview_windows().into_values().collect();
+    if windows.len() <= 1 {
+        return windows;
+    }
+    let focused: Vec<_> = windows
+        .into_iter()
+        .filter(|window| window.is_focused().unwrap_or(false))
+        .collect();
+    if focused.is_empty() {
+        log::warn!("Cannot determine which window exceeded the resource limits, skipping reload");
+    }
+    focused
+}
+
+impl WatchdogState {
+    /**
+     * 收集应用主进程及其所有子进程的资源占用
+     *
+     * 先用轻量刷新发现进程树，再只为应用自身的进程读取 CPU 与内存
+     */
+    fn collect_usage(&self) -> AppResourceUsage {
+        let Ok(self_pid) = sysinfo::get_current_pid() else {
+            return AppResourceUsage {
+                total_memory: 0,
+                total_cpu_usage: 0.0,
+                processes: Vec::new(),
+            };
+        };
+
+        let mut systems = self.systems.lock().unwrap();
+        let WatchdogSystems { tree, usage } = &mut *systems;
+        tree.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing().without_tasks(),
+        );
+        let pids = descendants(tree, self_pid);
+        // 同时刷新上次记录的进程，已退出的会被移除
+        let mut refresh: Vec<Pid> = usage.processes().keys().copied().collect();
+        refresh.extend(pids.iter().copied());
+        refresh.sort_unstable();
+        refresh.dedup();
+        usage.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&refresh),
+            true,
+            ProcessRefreshKind::nothing().with_cpu().with_memory(),
+        );
+
+        let processes: Vec<ProcessUsage> = pids
+            .iter()
+            .filter_map(|pid| usage.process(*pid))
+            .map(|process| ProcessUsage {
+                pid: process.pid().as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                is_main: process.pid() == self_pid,
+                memory: process.memory(),
+                cpu_usage: process.cpu_usage(),
+                thread_count: process.tasks().map(|tasks| tasks.len()),
+                open_files: process.open_files(),
+            })
+            .collect();
+
+        AppResourceUsage {
+            total_memory: processes.iter().map(|p| p.memory).sum(),
+            total_cpu_usage: processes.iter().map(|p| p.cpu_usage).sum(),
+            processes,
+        }
+    }
+}
+
+/**
+ * 获取指定进程及其全部后代进程的 PID（不含线程）
+ */
+fn descendants(system: &System, root: Pid) -> Vec<Pid> {
+    let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    for (pid, process) in system.processes() {
+        if process.thread_kind().is_some() {
+            continue;
+        }
+        if let Some(parent) = process.parent() {
+            children.entry(parent).or_default().push(*pid);
+        }
+    }
+
+    let mut result = vec![root];
+    let mut index = 0;
+    while index < result.len() {
+        if let Some(pids) = children.get(&result[index]) {
+            result.extend(pids.iter().copied());
+        }
+        index += 1;
+    }
+    result
+}
+
+/**
+ * 获取应用自身的资源占用
+ */
+#[tauri::command]
+pub async fn get_app_resource_usage(app: AppHandle) -> Result<AppResourceUsage, String> {
+    tauri::async_runtime::spawn_blocking(move || app.state::<WatchdogState>().collect_usage())
+        .await
+        .map_err(|e| t!("error-process-query", error = e))
+}
+
+/**
+ * 获取看门狗配置
+ */
+#[tauri::command]
+pub async fn get_watchdog_config(
+    settings: State<'_, SettingsState>,
+) -> Result<WatchdogConfig, String> {
+    Ok(settings.get().watchdog)
+}
+
+/**
+ * 更新看门狗配置并保存到设置
+ */
+#[tauri::command]
+pub async fn set_watchdog_config(
+    app: AppHandle,
+    settings: State<'_, SettingsState>,
+    watchdog: State<'_, WatchdogState>,
+    config: WatchdogConfig,
+) -> Result<(), String> {
+    settings.update(&app, |settings| settings.watchdog = config)?;
+    watchdog.exceeded.lock().unwrap().clear();
+    Ok(())
+}
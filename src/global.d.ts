@@ -24,6 +24,8 @@ type DataType<R> = {
 }
 
 type SystemInfo = {
+  group: string
+  group_name: string
   key: string
   name: string
   value: any